@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+/// SPL Noop program used by Bubblegum as its log wrapper.
+pub const SPL_NOOP_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// SPL Account Compression program that owns Bubblegum merkle trees.
+pub const SPL_ACCOUNT_COMPRESSION_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// Metaplex Token Auth Rules program that evaluates pNFT rule sets.
+pub const MPL_TOKEN_AUTH_RULES_ID: Pubkey = pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
@@ -17,4 +17,142 @@ pub enum ErrorCode {
     InvalidMasterEdition,
     #[msg("Invalid collection metadata")]
     InvalidCollectionMetadata,
+    #[msg("Collection mint must sign when the program creates the collection")]
+    CollectionMintNotSigner,
+    #[msg("Missing accounts required to create the collection NFT")]
+    MissingCollectionAccounts,
+    #[msg("Instruction does not match the campaign asset standard")]
+    InvalidAssetStandard,
+    #[msg("Merkle tree cannot hold the campaign max supply")]
+    TreeTooSmall,
+    #[msg("Campaign merkle tree already set")]
+    TreeAlreadySet,
+    #[msg("Missing accounts required to mint a programmable NFT")]
+    MissingProgrammableAccounts,
+    #[msg("Rule set does not match the campaign rule set")]
+    InvalidRuleSet,
+    #[msg("Missing accounts required to print an edition")]
+    MissingPrintAccounts,
+    #[msg("Open edition end time must be in the future")]
+    InvalidEndTime,
+    #[msg("Sale window has ended")]
+    SaleEnded,
+    #[msg("Sale window has not ended yet")]
+    SaleNotEnded,
+    #[msg("Campaign is not an open edition")]
+    NotOpenEdition,
+    #[msg("Campaign already finalized")]
+    CampaignFinalized,
+    #[msg("Quantity must be greater than zero")]
+    InvalidQuantity,
+    #[msg("Remaining accounts do not match the requested quantity")]
+    InvalidRemainingAccounts,
+    #[msg("Supply shards are not configured for this campaign")]
+    ShardsNotConfigured,
+    #[msg("Supply shards are already configured")]
+    ShardsAlreadyConfigured,
+    #[msg("Campaign supply is sharded, mint through a supply shard")]
+    ShardedSupply,
+    #[msg("Invalid supply shard")]
+    InvalidShard,
+    #[msg("Wallet has reached the campaign mint limit")]
+    WalletLimitReached,
+    #[msg("Missing per-wallet mint counter account")]
+    MissingWalletCounter,
+    #[msg("Campaign is allowlist-only, an allowlist proof is required")]
+    AllowlistProofRequired,
+    #[msg("Allowlist proof does not match the campaign root")]
+    InvalidAllowlistProof,
+    #[msg("Allowlist allocation fully used")]
+    AllowlistAllocationUsed,
+    #[msg("Campaign sells in phases, use process_mint")]
+    PhasedSale,
+    #[msg("No sale phase is active")]
+    NoActivePhase,
+    #[msg("Missing campaign phases account")]
+    MissingPhasesAccount,
+    #[msg("Phase mint cap reached")]
+    PhaseSoldOut,
+    #[msg("Phases must be ordered, non-overlapping and at most the maximum count")]
+    InvalidPhases,
+    #[msg("Campaign is token gated, gate token account and metadata are required")]
+    TokenGateRequired,
+    #[msg("Gate token is not a held, verified NFT of the gate collection")]
+    InvalidGateToken,
+    #[msg("Gating NFT has already been used for this campaign")]
+    GateTokenUsed,
+    #[msg("Campaign requires a signed voucher, instructions sysvar and nonce account")]
+    VoucherRequired,
+    #[msg("Voucher does not match this mint")]
+    InvalidVoucher,
+    #[msg("Voucher has expired")]
+    VoucherExpired,
+    #[msg("Voucher nonce already used")]
+    VoucherUsed,
+    #[msg("Missing or invalid ed25519 voucher signature instruction")]
+    InvalidVoucherSignature,
+    #[msg("Campaign requires its cosigner to sign the mint")]
+    MissingCosigner,
+    #[msg("Missing campaign guard set account")]
+    MissingGuardSet,
+    #[msg("A campaign guard rejected the mint")]
+    GuardFailed,
+    #[msg("Too many guards")]
+    TooManyGuards,
+    #[msg("Missing or wrong campaign guard program")]
+    MissingGuardProgram,
+    #[msg("Missing or wrong post-mint hook program")]
+    MissingPostMintHook,
+    #[msg("Coupon code given without its coupon account")]
+    MissingCoupon,
+    #[msg("Coupon has no uses left")]
+    CouponExhausted,
+    #[msg("Coupon has expired")]
+    CouponExpired,
+    #[msg("Invalid coupon discount")]
+    InvalidDiscount,
+    #[msg("Buyer rebate must be at most 10000 bps")]
+    InvalidRebate,
+    #[msg("Invalid Dutch auction parameters")]
+    InvalidAuction,
+    #[msg("Pricing can only change before the first mint")]
+    PricingLocked,
+    #[msg("Dutch auction has not settled yet")]
+    AuctionNotSettled,
+    #[msg("Campaign is not a Dutch auction in rebate mode")]
+    NotRebateAuction,
+    #[msg("Missing auction receipt account")]
+    MissingAuctionReceipt,
+    #[msg("Missing or wrong affiliate receiver or stats account")]
+    MissingAffiliateAccounts,
+    #[msg("Campaign uses dynamic pricing, use process_mint")]
+    DynamicPricing,
+    #[msg("Setting is not supported on sharded campaigns")]
+    ShardedUnsupported,
+    #[msg("Metadata is not an unverified item of this campaign")]
+    ItemNotPending,
+    #[msg("Campaign caps mints per wallet, use process_mint")]
+    WalletLimitedSale,
+    #[msg("Campaign is allowlist-only, use process_mint")]
+    AllowlistSale,
+    #[msg("Campaign is token gated, use process_mint")]
+    TokenGatedSale,
+    #[msg("Campaign requires a signed voucher, use process_mint")]
+    VoucherSale,
+    #[msg("Campaign requires its cosigner, use process_mint")]
+    CosignedSale,
+    #[msg("Campaign has a guard set, use process_mint")]
+    GuardedSale,
+    #[msg("Campaign has a guard program, use process_mint or process_mint_batch")]
+    GuardProgramSale,
+    #[msg("Post-mint hook reported a failure")]
+    PostMintHookFailed,
+    #[msg("Campaign has a post-mint hook, use process_mint")]
+    HookedSale,
+    #[msg("Auction receipt passed for a mint that escrows nothing")]
+    UnexpectedAuctionReceipt,
+    #[msg("Affiliate does not match the affiliate receiver")]
+    AffiliateMismatch,
+    #[msg("Campaign charges a bot tax, use process_mint")]
+    BotTaxedSale,
 }
\ No newline at end of file
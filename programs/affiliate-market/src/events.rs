@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Emitted for every `process_mint`, so indexers can attribute gift mints.
+#[event]
+pub struct MintReceipt {
+    pub campaign: Pubkey,
+    pub nft_mint: Pubkey,
+    /// Wallet that paid the price
+    pub buyer: Pubkey,
+    /// Wallet that paid the rent (the buyer unless sponsored by a backend)
+    pub payer: Pubkey,
+    /// Wallet that received the NFT (the buyer unless gifted)
+    pub recipient: Pubkey,
+    pub affiliate: Option<Pubkey>,
+    pub price: u64,
+}
+
+/// Emitted when a mint fails a sale gate in bot tax mode and the buyer is
+/// charged the penalty instead.
+#[event]
+pub struct BotTaxCharged {
+    pub campaign: Pubkey,
+    pub buyer: Pubkey,
+    pub lamports: u64,
+    /// Error code of the failed gate
+    pub error_code: u32,
+}
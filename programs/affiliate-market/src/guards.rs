@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+
+use crate::instructions::affiliate::payment::transfer_lamports;
+use crate::instructions::affiliate::token_gate::verify_gate_holding;
+use crate::error::ErrorCode;
+
+/// A mint pre-condition stored in a campaign's guard set. Variants are Borsh
+/// encoded (one tag byte plus config) and read their accounts, in order, from
+/// the mint instruction's remaining accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum Guard {
+    /// Mints open at `start`
+    StartDate { start: i64 },
+    /// Mints close at `end`
+    EndDate { end: i64 },
+    /// Only `address` may buy
+    AddressGate { address: Pubkey },
+    /// `signer` must sign the mint. Accounts: [signer]
+    ThirdPartySigner { signer: Pubkey },
+    /// Extra lamports from the buyer to `destination`. Accounts: [destination]
+    SolPayment { lamports: u64, destination: Pubkey },
+    /// Buyer holds a verified NFT of `collection`. Accounts: [token account, metadata]
+    TokenGate { collection: Pubkey },
+}
+
+impl Guard {
+    /// Largest serialized guard: tag + SolPayment config
+    pub const MAX_SIZE: usize = 1 + 8 + 32; // 41 bytes
+
+    /// Number of remaining accounts this guard reads.
+    pub fn account_count(&self) -> usize {
+        match self {
+            Guard::ThirdPartySigner { .. } | Guard::SolPayment { .. } => 1,
+            Guard::TokenGate { .. } => 2,
+            _ => 0,
+        }
+    }
+
+    /// Checks the pre-condition without side effects.
+    pub fn validate<'info>(&self, ctx: &GuardContext<'_, 'info>, accounts: &[AccountInfo<'info>]) -> Result<()> {
+        match self {
+            Guard::StartDate { start } => {
+                require!(ctx.now >= *start, ErrorCode::GuardFailed);
+            }
+            Guard::EndDate { end } => {
+                require!(ctx.now < *end, ErrorCode::GuardFailed);
+            }
+            Guard::AddressGate { address } => {
+                require_keys_eq!(ctx.buyer.key(), *address, ErrorCode::GuardFailed);
+            }
+            Guard::ThirdPartySigner { signer } => {
+                require_keys_eq!(accounts[0].key(), *signer, ErrorCode::GuardFailed);
+                require!(accounts[0].is_signer, ErrorCode::GuardFailed);
+            }
+            Guard::SolPayment { destination, .. } => {
+                require_keys_eq!(accounts[0].key(), *destination, ErrorCode::GuardFailed);
+            }
+            Guard::TokenGate { collection } => {
+                require_keys_eq!(*accounts[0].owner, token::ID, ErrorCode::InvalidGateToken);
+                let token_account = TokenAccount::try_deserialize(&mut &accounts[0].try_borrow_data()?[..])?;
+                verify_gate_holding(&ctx.buyer.key(), &token_account, &accounts[1], collection)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Performs the guard's effect once every guard has passed.
+    pub fn apply<'info>(&self, ctx: &GuardContext<'_, 'info>, accounts: &[AccountInfo<'info>]) -> Result<()> {
+        if let Guard::SolPayment { lamports, .. } = self {
+            transfer_lamports(ctx.buyer, &accounts[0], ctx.system_program, *lamports)?;
+        }
+        Ok(())
+    }
+}
+
+/// Mint data guards can inspect.
+pub struct GuardContext<'a, 'info> {
+    pub buyer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub now: i64,
+}
+
+/// Ordered guards of a campaign, seeded by `[b"guard_set", campaign]`.
+#[account]
+pub struct GuardSet {
+    pub guards: Vec<Guard>,
+}
+
+impl GuardSet {
+    pub const MAX_GUARDS: usize = 16;
+    pub const SIZE: usize = 4 + Guard::MAX_SIZE * Self::MAX_GUARDS; // 660 bytes
+
+    /// Validates every guard in order. Each guard takes the next
+    /// `account_count` remaining accounts; all of them must be used.
+    pub fn validate<'info>(&self, ctx: &GuardContext<'_, 'info>, accounts: &[AccountInfo<'info>]) -> Result<()> {
+        self.walk(accounts, |guard, slice| guard.validate(ctx, slice))
+    }
+
+    /// Applies every guard's effect, walking the accounts like `validate`.
+    pub fn apply<'info>(&self, ctx: &GuardContext<'_, 'info>, accounts: &[AccountInfo<'info>]) -> Result<()> {
+        self.walk(accounts, |guard, slice| guard.apply(ctx, slice))
+    }
+
+    fn walk<'info>(
+        &self,
+        accounts: &[AccountInfo<'info>],
+        mut f: impl FnMut(&Guard, &[AccountInfo<'info>]) -> Result<()>,
+    ) -> Result<()> {
+        let mut offset = 0;
+        for guard in &self.guards {
+            let end = offset + guard.account_count();
+            let slice = accounts.get(offset..end).ok_or(ErrorCode::InvalidRemainingAccounts)?;
+            f(guard, slice)?;
+            offset = end;
+        }
+        require!(offset == accounts.len(), ErrorCode::InvalidRemainingAccounts);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(key: Pubkey, is_signer: bool) -> AccountInfo<'static> {
+        let key = Box::leak(Box::new(key));
+        let lamports = Box::leak(Box::new(0u64));
+        let owner = Box::leak(Box::new(Pubkey::default()));
+        AccountInfo::new(key, is_signer, false, lamports, &mut [], owner, false, 0)
+    }
+
+    fn guard_set(guards: Vec<Guard>) -> GuardSet {
+        GuardSet { guards }
+    }
+
+    fn walked(set: &GuardSet, accounts: &[AccountInfo<'static>]) -> Result<Vec<Vec<Pubkey>>> {
+        let mut slices = vec![];
+        set.walk(accounts, |_, slice| {
+            slices.push(slice.iter().map(|a| a.key()).collect());
+            Ok(())
+        })?;
+        Ok(slices)
+    }
+
+    #[test]
+    fn walk_hands_each_guard_its_accounts_in_order() {
+        let signer = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let set = guard_set(vec![
+            Guard::StartDate { start: 0 },
+            Guard::ThirdPartySigner { signer },
+            Guard::SolPayment { lamports: 1, destination },
+        ]);
+        let accounts = [account(signer, true), account(destination, false)];
+        assert_eq!(walked(&set, &accounts).unwrap(), vec![vec![], vec![signer], vec![destination]]);
+    }
+
+    #[test]
+    fn walk_rejects_missing_accounts() {
+        let set = guard_set(vec![Guard::TokenGate { collection: Pubkey::new_unique() }]);
+        let accounts = [account(Pubkey::new_unique(), false)];
+        assert_eq!(walked(&set, &accounts).unwrap_err(), error!(ErrorCode::InvalidRemainingAccounts));
+    }
+
+    #[test]
+    fn walk_rejects_unconsumed_accounts() {
+        let signer = Pubkey::new_unique();
+        let set = guard_set(vec![Guard::ThirdPartySigner { signer }]);
+        let accounts = [account(signer, true), account(Pubkey::new_unique(), false)];
+        assert_eq!(walked(&set, &accounts).unwrap_err(), error!(ErrorCode::InvalidRemainingAccounts));
+        assert_eq!(walked(&guard_set(vec![]), &accounts[..1]).unwrap_err(), error!(ErrorCode::InvalidRemainingAccounts));
+    }
+
+    #[test]
+    fn validate_checks_guards_against_their_accounts() {
+        let buyer = account(Pubkey::new_unique(), true);
+        let system_program = account(anchor_lang::system_program::ID, false);
+        let ctx = GuardContext { buyer: &buyer, system_program: &system_program, now: 100 };
+        let signer = Pubkey::new_unique();
+        let set = guard_set(vec![
+            Guard::StartDate { start: 100 },
+            Guard::EndDate { end: 101 },
+            Guard::AddressGate { address: buyer.key() },
+            Guard::ThirdPartySigner { signer },
+        ]);
+
+        assert!(set.validate(&ctx, &[account(signer, true)]).is_ok());
+        assert_eq!(set.validate(&ctx, &[account(signer, false)]).unwrap_err(), error!(ErrorCode::GuardFailed));
+        assert_eq!(
+            set.validate(&ctx, &[account(Pubkey::new_unique(), true)]).unwrap_err(),
+            error!(ErrorCode::GuardFailed)
+        );
+        let late = GuardContext { now: 101, ..ctx };
+        assert_eq!(set.validate(&late, &[account(signer, true)]).unwrap_err(), error!(ErrorCode::GuardFailed));
+    }
+}
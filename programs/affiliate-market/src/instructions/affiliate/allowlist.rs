@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// Allowlist entry claimed by the buyer plus its Merkle proof. Leaves are
+/// keccak(wallet, max_mints as u64 LE, price as Borsh Option<u64>) and pairs
+/// are hashed in sorted order.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    pub max_mints: u64,
+    pub price: Option<u64>,
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl AllowlistProof {
+    pub fn leaf(&self, wallet: &Pubkey) -> [u8; 32] {
+        let price = self.price.try_to_vec().unwrap_or_default();
+        hashv(&[wallet.as_ref(), &self.max_mints.to_le_bytes(), &price]).to_bytes()
+    }
+
+    /// Returns true if the wallet's leaf is in the tree with `root`.
+    pub fn verify(&self, wallet: &Pubkey, root: &[u8; 32]) -> bool {
+        let mut node = self.leaf(wallet);
+        for sibling in &self.proof {
+            node = if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            };
+        }
+        node == *root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(max_mints: u64, price: Option<u64>, proof: Vec<[u8; 32]>) -> AllowlistProof {
+        AllowlistProof { max_mints, price, proof }
+    }
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[&a, &b]).to_bytes()
+        } else {
+            hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    /// Two-leaf tree of `alice` (2 mints at 500) and `bob` (1 mint, list price).
+    fn tree() -> (Pubkey, Pubkey, [u8; 32], [u8; 32], [u8; 32]) {
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        let alice_leaf = entry(2, Some(500), vec![]).leaf(&alice);
+        let bob_leaf = entry(1, None, vec![]).leaf(&bob);
+        (alice, bob, alice_leaf, bob_leaf, parent(alice_leaf, bob_leaf))
+    }
+
+    #[test]
+    fn leaf_encodes_wallet_allocation_and_price() {
+        let wallet = Pubkey::new_unique();
+        let expected = hashv(&[wallet.as_ref(), &2u64.to_le_bytes(), &[1, 244, 1, 0, 0, 0, 0, 0, 0]]).to_bytes();
+        assert_eq!(entry(2, Some(500), vec![]).leaf(&wallet), expected);
+        let expected = hashv(&[wallet.as_ref(), &2u64.to_le_bytes(), &[0]]).to_bytes();
+        assert_eq!(entry(2, None, vec![]).leaf(&wallet), expected);
+    }
+
+    #[test]
+    fn valid_proof_verifies() {
+        let (alice, bob, alice_leaf, bob_leaf, root) = tree();
+        assert!(entry(2, Some(500), vec![bob_leaf]).verify(&alice, &root));
+        assert!(entry(1, None, vec![alice_leaf]).verify(&bob, &root));
+    }
+
+    #[test]
+    fn wrong_leaf_fails() {
+        let (alice, bob, _, bob_leaf, root) = tree();
+        // Another wallet reusing alice's proof
+        assert!(!entry(2, Some(500), vec![bob_leaf]).verify(&bob, &root));
+        // Alice claiming a larger allocation
+        assert!(!entry(3, Some(500), vec![bob_leaf]).verify(&alice, &root));
+    }
+
+    #[test]
+    fn wrong_price_fails() {
+        let (alice, _, _, bob_leaf, root) = tree();
+        assert!(!entry(2, Some(400), vec![bob_leaf]).verify(&alice, &root));
+        assert!(!entry(2, None, vec![bob_leaf]).verify(&alice, &root));
+    }
+
+    #[test]
+    fn empty_proof_matches_only_a_single_leaf_root() {
+        let (alice, _, alice_leaf, _, root) = tree();
+        assert!(!entry(2, Some(500), vec![]).verify(&alice, &root));
+        assert!(entry(2, Some(500), vec![]).verify(&alice, &alice_leaf));
+    }
+}
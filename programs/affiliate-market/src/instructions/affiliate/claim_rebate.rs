@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use super::payment::split_price;
+use crate::state::{AffiliateStats, AuctionReceipt, Campaign, PricingMode};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ClaimRebate<'info> {
+    /// Caller, pays for the affiliate stats if they do not exist yet
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = buyer,
+        has_one = campaign,
+        has_one = buyer,
+        seeds = [b"auction_receipt", campaign.key().as_ref(), auction_receipt.nft_mint.as_ref()],
+        bump = auction_receipt.bump
+    )]
+    pub auction_receipt: Account<'info, AuctionReceipt>,
+
+    /// CHECK: Buyer recorded on the receipt - receives the rebate and rent
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Creator account verified via campaign.creator constraint
+    #[account(mut, address = campaign.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: Affiliate recorded on the receipt, required when it has one
+    #[account(mut)]
+    pub affiliate_receiver: Option<UncheckedAccount<'info>>,
+
+    /// Affiliate stats PDA of the recorded affiliate
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AffiliateStats::SIZE,
+        seeds = [
+            b"affiliate",
+            campaign.key().as_ref(),
+            auction_receipt.affiliate.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub affiliate_stats: Option<Account<'info, AffiliateStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Settles one Dutch auction rebate-mode mint once the auction has cleared:
+/// at the last price paid when sold out or finalized, else at its floor. Of the escrow, the clearing price
+/// above the floor goes to the creator and affiliate at the recorded fee; the
+/// rest is refunded to the buyer together with the receipt rent. Anyone can
+/// call it.
+pub fn claim_rebate_instruction(ctx: Context<ClaimRebate>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let PricingMode::DutchAuction(auction) = campaign.pricing else {
+            return err!(ErrorCode::NotRebateAuction);
+        };
+        require!(auction.rebate, ErrorCode::NotRebateAuction);
+
+        // 1) Clearing price
+        let now = Clock::get()?.unix_timestamp;
+        let sold_out = !campaign.open_edition && campaign.minted >= campaign.max_supply;
+        let clearing_price = auction.settled_price(sold_out || campaign.finalized, campaign.clearing_price, now)?;
+
+        // 2) Part of the escrow owed to creator and affiliate
+        let receipt = &ctx.accounts.auction_receipt;
+        let owed = receipt.escrowed.min(clearing_price.saturating_sub(auction.floor_price));
+        let (mut creator_cut, affiliate_cut) = split_price(owed, receipt.affiliate_fee_bps)?;
+
+        let receipt_info = receipt.to_account_info();
+        match receipt.affiliate {
+            Some(affiliate) => {
+                let (Some(affiliate_receiver), Some(affiliate_stats)) = (
+                    ctx.accounts.affiliate_receiver.as_ref(),
+                    ctx.accounts.affiliate_stats.as_mut(),
+                ) else {
+                    return err!(ErrorCode::MissingAffiliateAccounts);
+                };
+                require_keys_eq!(affiliate_receiver.key(), affiliate, ErrorCode::MissingAffiliateAccounts);
+                **receipt_info.try_borrow_mut_lamports()? -= affiliate_cut;
+                **affiliate_receiver.try_borrow_mut_lamports()? += affiliate_cut;
+                affiliate_stats.total_earned = affiliate_stats
+                    .total_earned
+                    .checked_add(affiliate_cut)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            None => {
+                creator_cut = creator_cut.checked_add(affiliate_cut).ok_or(ErrorCode::MathOverflow)?;
+            }
+        }
+        **receipt_info.try_borrow_mut_lamports()? -= creator_cut;
+        **ctx.accounts.creator.try_borrow_mut_lamports()? += creator_cut;
+
+        // 3) The rebate and rent go back to the buyer when the receipt closes
+        Ok(())
+    }
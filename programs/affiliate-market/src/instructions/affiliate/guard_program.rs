@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::{AccountMeta, Instruction}, program::invoke};
+
+/// Anchor discriminator of the guard interface instruction
+/// `check_mint(affiliate: Option<Pubkey>, quantity: u64)`, i.e. the first
+/// 8 bytes of sha256("global:check_mint").
+pub const CHECK_MINT_DISCRIMINATOR: [u8; 8] = [111, 157, 230, 142, 6, 58, 200, 73];
+
+/// Calls the campaign's guard program with read-only accounts [buyer, campaign].
+/// The buyer's signature is not forwarded, so a guard cannot move its funds.
+/// Any error from the guard aborts the transaction.
+pub fn invoke_guard_program<'info>(
+    guard_program: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+    campaign: &AccountInfo<'info>,
+    affiliate: Option<Pubkey>,
+    quantity: u64,
+) -> Result<()> {
+    let mut data = CHECK_MINT_DISCRIMINATOR.to_vec();
+    (affiliate, quantity).serialize(&mut data)?;
+
+    invoke(
+        &Instruction {
+            program_id: guard_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(buyer.key(), false),
+                AccountMeta::new_readonly(campaign.key(), false),
+            ],
+            data,
+        },
+        &[buyer.clone(), campaign.clone(), guard_program.clone()],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo};
+use mpl_token_metadata::instructions::{
+    CreateMetadataAccountV3Cpi, CreateMetadataAccountV3CpiAccounts, CreateMetadataAccountV3InstructionArgs,
+    CreateMasterEditionV3Cpi, CreateMasterEditionV3CpiAccounts, CreateMasterEditionV3InstructionArgs,
+    VerifySizedCollectionItemCpi, VerifySizedCollectionItemCpiAccounts,
+};
+use mpl_token_metadata::types::{Collection, DataV2};
+
+use crate::state::Campaign;
+
+/// Accounts used to turn an initialized, empty mint into a 1/1 master edition
+/// NFT naming the campaign's collection, still unverified.
+pub struct MasterEditionMint<'info> {
+    pub payer: AccountInfo<'info>,
+    pub nft_mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// Accounts used to verify an item into the campaign's sized collection.
+pub struct CollectionVerification<'info> {
+    pub payer: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+    pub collection_authority: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+impl<'info> MasterEditionMint<'info> {
+    /// Mints the token and creates metadata and master edition.
+    pub fn mint(
+        &self,
+        campaign: &Campaign,
+        campaign_key: Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        // 4) Mint token to buyer
+        let mint_auth_bump = campaign.mint_authority_bump;
+        let seeds_for_mint_auth: &[&[u8]] = &[b"mint_auth", campaign_key.as_ref(), &[mint_auth_bump]];
+        let signer_seeds_mint_auth = &[seeds_for_mint_auth];
+
+        let cpi_accounts_mint_to = MintTo {
+            mint: self.nft_mint.clone(),
+            to: self.token_account.clone(),
+            authority: self.mint_authority.clone(),
+        };
+        let cpi_ctx_mint = CpiContext::new_with_signer(self.token_program.clone(), cpi_accounts_mint_to, signer_seeds_mint_auth);
+        token::mint_to(cpi_ctx_mint, 1)?;
+
+        // 5) Create metadata via Metaplex CPI
+        let data_v2 = DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: Some(Collection { verified: false, key: self.collection_mint.key() }),
+            uses: None,
+        };
+
+        CreateMetadataAccountV3Cpi::new(
+            &self.token_metadata_program,
+            CreateMetadataAccountV3CpiAccounts {
+                metadata: &self.metadata,
+                mint: &self.nft_mint,
+                mint_authority: &self.mint_authority,
+                payer: &self.payer,
+                update_authority: (&self.mint_authority, true),
+                system_program: &self.system_program,
+                rent: Some(&self.rent),
+            },
+            CreateMetadataAccountV3InstructionArgs {
+                data: data_v2,
+                is_mutable: true,
+                collection_details: None,
+            },
+        )
+        .invoke_signed(signer_seeds_mint_auth)?;
+
+        // 6) Create master edition
+        CreateMasterEditionV3Cpi::new(
+            &self.token_metadata_program,
+            CreateMasterEditionV3CpiAccounts {
+                edition: &self.master_edition,
+                mint: &self.nft_mint,
+                update_authority: &self.mint_authority,
+                mint_authority: &self.mint_authority,
+                payer: &self.payer,
+                metadata: &self.metadata,
+                token_program: &self.token_program,
+                system_program: &self.system_program,
+                rent: Some(&self.rent),
+            },
+            CreateMasterEditionV3InstructionArgs { max_supply: Some(0) },
+        )
+        .invoke_signed(signer_seeds_mint_auth)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> CollectionVerification<'info> {
+    /// Verifies the item into the sized collection as the collection_auth PDA.
+    pub fn verify(&self, campaign: &Campaign, campaign_key: Pubkey) -> Result<()> {
+        let coll_auth_bump = campaign.collection_auth_bump;
+        let coll_auth_seeds: &[&[u8]] = &[b"collection_auth", campaign_key.as_ref(), &[coll_auth_bump]];
+        let signer_seeds_collection_auth = &[coll_auth_seeds];
+
+        VerifySizedCollectionItemCpi::new(
+            &self.token_metadata_program,
+            VerifySizedCollectionItemCpiAccounts {
+                metadata: &self.metadata,
+                collection_authority: &self.collection_authority,
+                payer: &self.payer,
+                collection_mint: &self.collection_mint,
+                collection: &self.collection_metadata,
+                collection_master_edition_account: &self.collection_master_edition,
+                collection_authority_record: None,
+            },
+        )
+        .invoke_signed(signer_seeds_collection_auth)?;
+
+        Ok(())
+    }
+}
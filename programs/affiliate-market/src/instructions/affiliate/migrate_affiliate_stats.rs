@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use super::payment::grow_account;
+use crate::state::AffiliateStats;
+
+#[derive(Accounts)]
+pub struct MigrateAffiliateStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: an AffiliateStats account of any layout, checked by owner and
+    /// discriminator since older layouts no longer deserialize
+    #[account(mut, owner = crate::ID)]
+    pub affiliate_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows an affiliate stats account created before `buyer_rebate_bps` to the
+/// current size; the rebate starts at zero. Anyone may pay for it.
+pub fn migrate_affiliate_stats_instruction(ctx: Context<MigrateAffiliateStats>) -> Result<()> {
+        let stats = ctx.accounts.affiliate_stats.to_account_info();
+        require!(
+            stats.try_borrow_data()?.starts_with(AffiliateStats::DISCRIMINATOR),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        grow_account(
+            &ctx.accounts.payer.to_account_info(),
+            &stats,
+            &ctx.accounts.system_program.to_account_info(),
+            8 + AffiliateStats::SIZE,
+        )
+    }
@@ -1,2 +1,31 @@
 pub use process_affiliate::*;
-pub mod process_affiliate;
\ No newline at end of file
+pub mod process_affiliate;
+pub use process_mint_core::*;
+pub mod process_mint_core;
+pub use process_mint_compressed::*;
+pub mod process_mint_compressed;
+pub use process_mint_token_2022::*;
+pub mod process_mint_token_2022;
+pub use process_mint_batch::*;
+pub mod process_mint_batch;
+pub mod payment;
+pub mod master_edition;
+pub use process_mint_sharded::*;
+pub mod process_mint_sharded;
+pub mod allowlist;
+pub use allowlist::AllowlistProof;
+pub mod token_gate;
+pub mod voucher;
+pub use voucher::MintVoucher;
+pub mod guard_program;
+pub mod post_mint_hook;
+pub use set_affiliate_rebate::*;
+pub mod set_affiliate_rebate;
+pub use claim_rebate::*;
+pub mod claim_rebate;
+pub use quote_prices::*;
+pub mod quote_prices;
+pub use verify_sharded_mint::*;
+pub mod verify_sharded_mint;
+pub use migrate_affiliate_stats::*;
+pub mod migrate_affiliate_stats;
\ No newline at end of file
@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::{invoke, invoke_signed}, system_instruction};
+
+use crate::state::{AffiliateStats, Campaign};
+use crate::error::ErrorCode;
+
+/// Splits a price into (creator_cut, affiliate_cut) using the campaign fee.
+pub fn split_price(price: u64, affiliate_fee_bps: u16) -> Result<(u64, u64)> {
+    let affiliate_cut = ((price as u128) * (affiliate_fee_bps as u128) / 10_000u128) as u64;
+    let creator_cut = price.checked_sub(affiliate_cut).ok_or(ErrorCode::MathOverflow)?;
+    Ok((creator_cut, affiliate_cut))
+}
+
+/// Requires a named affiliate to be the account that gets paid, so the
+/// affiliate checked by gates and vouchers is the one receiving the cut.
+pub fn check_affiliate(affiliate_maybe: Option<Pubkey>, affiliate_receiver: Pubkey) -> Result<()> {
+    if let Some(affiliate) = affiliate_maybe {
+        require_keys_eq!(affiliate, affiliate_receiver, ErrorCode::AffiliateMismatch);
+    }
+    Ok(())
+}
+
+/// Pays the creator and affiliate from the buyer. Without an affiliate the
+/// affiliate cut goes to the creator.
+pub fn pay_creator_and_affiliate<'info>(
+    buyer: &AccountInfo<'info>,
+    creator: &AccountInfo<'info>,
+    affiliate_receiver: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    affiliate_maybe: Option<Pubkey>,
+    creator_cut: u64,
+    affiliate_cut: u64,
+) -> Result<()> {
+    // Transfer creator_cut
+    if creator_cut > 0 {
+        transfer_lamports(buyer, creator, system_program, creator_cut)?;
+    }
+
+    // Transfer affiliate_cut if provided
+    if let Some(_affiliate_pk) = affiliate_maybe {
+        if affiliate_cut > 0 && affiliate_receiver.key() != Pubkey::default() {
+            transfer_lamports(buyer, affiliate_receiver, system_program, affiliate_cut)?;
+        }
+    } else {
+        // If no affiliate, send affiliate_cut to creator
+        if affiliate_cut > 0 {
+            transfer_lamports(buyer, creator, system_program, affiliate_cut)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Part of the affiliate cut the affiliate passes back to the buyer, per its
+/// `buyer_rebate_bps`. Zero when no affiliate is paid.
+pub fn buyer_rebate(
+    stats: &AffiliateStats,
+    affiliate_maybe: Option<Pubkey>,
+    affiliate_receiver: Pubkey,
+    affiliate_cut: u64,
+) -> u64 {
+    if affiliate_maybe.is_none() || affiliate_receiver == Pubkey::default() {
+        return 0;
+    }
+    ((affiliate_cut as u128) * (stats.buyer_rebate_bps as u128) / 10_000u128) as u64
+}
+
+/// Adds `quantity` mints and the earned cut to the affiliate's stats.
+pub fn record_affiliate_mint(
+    stats: &mut AffiliateStats,
+    affiliate_maybe: Option<Pubkey>,
+    affiliate_receiver: Pubkey,
+    quantity: u64,
+    affiliate_cut: u64,
+) -> Result<()> {
+    if let Some(_affiliate_pk) = affiliate_maybe {
+        if affiliate_receiver != Pubkey::default() {
+            stats.total_mints = stats.total_mints.checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+            stats.total_earned = stats.total_earned.checked_add(affiliate_cut).ok_or(ErrorCode::MathOverflow)?;
+        }
+    }
+    Ok(())
+}
+
+/// Pays `amount` back to the mint payer from the campaign rent sponsor PDA,
+/// capped at what the sponsor holds above its own rent-exempt minimum.
+pub fn reimburse_rent<'info>(
+    campaign: &Account<'info, Campaign>,
+    rent_sponsor: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let available = rent_sponsor.lamports().saturating_sub(Rent::get()?.minimum_balance(0));
+    let amount = amount.min(available);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let binding = campaign.key();
+    let sponsor_seeds: &[&[u8]] = &[b"rent_sponsor", binding.as_ref(), &[campaign.rent_sponsor_bump]];
+    invoke_signed(
+        &system_instruction::transfer(rent_sponsor.key, payer.key, amount),
+        &[rent_sponsor.clone(), payer.clone(), system_program.clone()],
+        &[sponsor_seeds],
+    )?;
+    Ok(())
+}
+
+/// Plain system transfer of `amount` lamports.
+pub fn transfer_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    invoke(
+        &system_instruction::transfer(from.key, to.key, amount),
+        &[from.clone(), to.clone(), system_program.clone()],
+    )?;
+    Ok(())
+}
+
+/// Creates a program-owned PDA of `space` bytes funded by `payer`, topping up
+/// lamports already sent to the address. Returns the lamports the payer paid.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<u64> {
+    let required = Rent::get()?.minimum_balance(space);
+    let current = account.lamports();
+    if current == 0 {
+        invoke_signed(
+            &system_instruction::create_account(payer.key, account.key, required, space as u64, owner),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+        return Ok(required);
+    }
+
+    let top_up = required.saturating_sub(current);
+    if top_up > 0 {
+        transfer_lamports(payer, account, system_program, top_up)?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(account.key, space as u64),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account.key, owner),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    Ok(top_up)
+}
+
+/// Grows a program-owned account to `space` bytes, topping up its rent from
+/// `payer`. The added bytes are zero, which decodes as the default of fields
+/// appended to the account since it was created. No-op when already that large.
+pub fn grow_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    if account.data_len() >= space {
+        return Ok(());
+    }
+    let top_up = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    if top_up > 0 {
+        transfer_lamports(payer, account, system_program, top_up)?;
+    }
+    account.resize(space)?;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke},
+};
+
+/// Anchor discriminator of the hook interface instruction
+/// `on_mint(mint: Pubkey, buyer: Pubkey, affiliate: Option<Pubkey>, price: u64) -> u32`,
+/// i.e. the first 8 bytes of sha256("global:on_mint").
+pub const ON_MINT_DISCRIMINATOR: [u8; 8] = [117, 64, 11, 222, 18, 173, 234, 186];
+
+/// Code reported for a hook that set no return data or data that is not a u32.
+pub const INVALID_RETURN_CODE: u32 = u32::MAX;
+
+/// Notifies the campaign's post-mint hook with read-only accounts
+/// [nft_mint, buyer, campaign]. No signatures are forwarded.
+///
+/// Hooks report failure by returning a nonzero u32 code as return data rather
+/// than erroring, since an error in the hook aborts the whole mint. Returns
+/// that code, 0 when the hook succeeded, or `INVALID_RETURN_CODE` when the
+/// hook returned no code or a malformed one.
+pub fn invoke_post_mint_hook<'info>(
+    hook_program: &AccountInfo<'info>,
+    nft_mint: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+    campaign: &AccountInfo<'info>,
+    affiliate: Option<Pubkey>,
+    price: u64,
+) -> Result<u32> {
+    let mut data = ON_MINT_DISCRIMINATOR.to_vec();
+    (nft_mint.key(), buyer.key(), affiliate, price).serialize(&mut data)?;
+
+    invoke(
+        &Instruction {
+            program_id: hook_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(nft_mint.key(), false),
+                AccountMeta::new_readonly(buyer.key(), false),
+                AccountMeta::new_readonly(campaign.key(), false),
+            ],
+            data,
+        },
+        &[nft_mint.clone(), buyer.clone(), campaign.clone(), hook_program.clone()],
+    )?;
+
+    let code = match get_return_data() {
+        Some((program_id, data)) if program_id == hook_program.key() => {
+            u32::try_from_slice(&data).unwrap_or(INVALID_RETURN_CODE)
+        }
+        _ => INVALID_RETURN_CODE,
+    };
+    Ok(code)
+}
@@ -1,30 +1,59 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, MintTo},
+    associated_token::{self, AssociatedToken, Create},
+    token::{self, InitializeMint2, Mint, MintTo, Token, TokenAccount},
 };
+use anchor_lang::solana_program::sysvar::instructions::ID as SYSVAR_INSTRUCTIONS_ID;
 use mpl_token_metadata::instructions::{
-    CreateMetadataAccountV3Cpi, CreateMetadataAccountV3CpiAccounts, CreateMetadataAccountV3InstructionArgs,
-    CreateMasterEditionV3Cpi, CreateMasterEditionV3CpiAccounts, CreateMasterEditionV3InstructionArgs,
-    VerifySizedCollectionItemCpi, VerifySizedCollectionItemCpiAccounts,
+    CreateV1Cpi, CreateV1CpiAccounts, CreateV1InstructionArgs,
+    MintV1Cpi, MintV1CpiAccounts, MintV1InstructionArgs,
+    VerifyCollectionV1Cpi, VerifyCollectionV1CpiAccounts,
+    MintNewEditionFromMasterEditionViaTokenCpi, MintNewEditionFromMasterEditionViaTokenCpiAccounts,
+    MintNewEditionFromMasterEditionViaTokenInstructionArgs,
 };
-use mpl_token_metadata::types::DataV2;
+use mpl_token_metadata::types::{
+    Collection, MintNewEditionFromMasterEditionViaTokenArgs, PrintSupply, TokenStandard,
+};
+use mpl_token_metadata::accounts::{MasterEdition, Metadata};
 use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
-use anchor_lang::solana_program::{program::invoke_signed, program::invoke, system_instruction};
 
-use crate::state::{AffiliateStats, Campaign};
+use super::allowlist::AllowlistProof;
+use super::guard_program::invoke_guard_program;
+use super::master_edition::{CollectionVerification, MasterEditionMint};
+use super::post_mint_hook::invoke_post_mint_hook;
+use super::token_gate::verify_gate_holding;
+use super::voucher::{verify_ed25519_instruction, MintVoucher};
+use super::payment::{
+    buyer_rebate, check_affiliate, create_pda_account, pay_creator_and_affiliate, record_affiliate_mint, reimburse_rent,
+    split_price, transfer_lamports,
+};
+use crate::constants::MPL_TOKEN_AUTH_RULES_ID;
+use crate::events::{BotTaxCharged, MintReceipt};
+use crate::guards::{GuardContext, GuardSet};
+use crate::state::{
+    AffiliateStats, AllowlistUsage, AssetStandard, AuctionReceipt, Campaign, CampaignPhases, Coupon, GateUsage,
+    PricingMode, VoucherNonce, WalletMints,
+};
 use crate::error::ErrorCode;
 
 
 
 
 #[derive(Accounts)]
-#[instruction(affiliate_maybe: Option<Pubkey>, name: String, symbol: String, uri: String)]
+#[instruction(affiliate_maybe: Option<Pubkey>, name: String, symbol: String, uri: String, allowlist: Option<AllowlistProof>, voucher: Option<MintVoucher>, coupon_code: Option<String>)]
 pub struct ProcessMint<'info> {
+    /// Pays rent for the accounts created by the mint (may be a sponsor backend)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pays the price
     #[account(mut)]
     pub buyer: Signer<'info>,
 
+    /// Backend cosigner, required when the campaign sets one
+    pub cosigner: Option<Signer<'info>>,
+
     #[account(mut, has_one = collection_mint)]
     pub campaign: Account<'info, Campaign>,
 
@@ -36,25 +65,22 @@ pub struct ProcessMint<'info> {
     #[account(mut)]
     pub affiliate_receiver: UncheckedAccount<'info>,
 
-    /// NFT mint PDA - initialized by Anchor
+    /// CHECK: NFT mint PDA - created in the handler once the sale gates pass,
+    /// so bot-taxed attempts leave nothing behind at this index
     #[account(
-        init,
-        payer = buyer,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
+        mut,
         seeds = [b"nft_mint", campaign.key().as_ref(), &campaign.minted.to_le_bytes()],
         bump
     )]
-    pub nft_mint: Account<'info, Mint>,
+    pub nft_mint: UncheckedAccount<'info>,
 
-    /// Buyer's associated token account
-    #[account(
-        init_if_needed,
-        payer = buyer,
-        associated_token::mint = nft_mint,
-        associated_token::authority = buyer
-    )]
-    pub buyer_ata: Account<'info, TokenAccount>,
+    /// CHECK: NFT owner for gift mints - any wallet, defaults to buyer
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Associated token account of the NFT owner (recipient, else buyer)
+    /// - created idempotently, address verified by the associated token program
+    #[account(mut)]
+    pub buyer_ata: UncheckedAccount<'info>,
 
     /// CHECK: Mint authority PDA - verified by seeds constraint
     #[account(
@@ -68,6 +94,7 @@ pub struct ProcessMint<'info> {
     pub metadata: UncheckedAccount<'info>,
 
     /// CHECK: Master edition account will be created by Metaplex CPI
+    /// (the print's edition account in print edition mode)
     #[account(mut)]
     pub master_edition: UncheckedAccount<'info>,
 
@@ -89,10 +116,166 @@ pub struct ProcessMint<'info> {
     )]
     pub collection_authority: UncheckedAccount<'info>,
 
+    /// CHECK: pNFT token record - created by Token Metadata MintV1
+    #[account(mut)]
+    pub token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: pNFT rule set - verified against campaign.rule_set
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program
+    #[account(address = MPL_TOKEN_AUTH_RULES_ID)]
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Instructions sysvar, required by Token Metadata for pNFTs and
+    /// to read the ed25519 instruction of a voucher
+    #[account(address = SYSVAR_INSTRUCTIONS_ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master token account held by mint_auth - verified by address
+    /// constraint against the campaign master mint
+    #[account(
+        address = associated_token::get_associated_token_address(
+            &mint_authority.key(),
+            &print_master_mint(&campaign.key())
+        )
+    )]
+    pub print_master_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master metadata account - verified by address constraint
+    #[account(mut, address = Metadata::find_pda(&print_master_mint(&campaign.key())).0)]
+    pub print_master_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master edition account of the master mint - verified by address constraint
+    #[account(mut, address = MasterEdition::find_pda(&print_master_mint(&campaign.key())).0)]
+    pub print_master_edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Edition marker PDA - created by Token Metadata
+    #[account(mut)]
+    pub edition_marker: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Campaign rent sponsor PDA - system account funded by the creator
+    #[account(
+        mut,
+        seeds = [b"rent_sponsor", campaign.key().as_ref()],
+        bump = campaign.rent_sponsor_bump
+    )]
+    pub rent_sponsor: Option<UncheckedAccount<'info>>,
+
+    /// Per-wallet mint counter of the NFT owner, required when the campaign
+    /// sets max_per_wallet
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WalletMints::SIZE,
+        seeds = [
+            b"buyer",
+            campaign.key().as_ref(),
+            recipient.as_ref().map_or(buyer.key(), |r| r.key()).as_ref()
+        ],
+        bump
+    )]
+    pub wallet_mints: Option<Account<'info, WalletMints>>,
+
+    /// Usage of the buyer's allowlist leaf, required when the campaign has an
+    /// allowlist root
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AllowlistUsage::SIZE,
+        seeds = [
+            b"allowlist",
+            campaign.key().as_ref(),
+            allowlist.as_ref().map(|a| a.leaf(&buyer.key())).unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub allowlist_usage: Option<Account<'info, AllowlistUsage>>,
+
+    /// Sale phases, required when the campaign is phased
+    #[account(
+        mut,
+        seeds = [b"phases", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_phases: Option<Account<'info, CampaignPhases>>,
+
+    /// Buyer's token account holding the gating NFT, required when the
+    /// campaign is token gated
+    pub gate_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Metadata of the gating NFT - verified in verify_gate_holding
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Use record of the gating NFT, required for single-use gates
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + GateUsage::SIZE,
+        seeds = [
+            b"gate",
+            campaign.key().as_ref(),
+            gate_token_account.as_ref().map(|t| t.mint).unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub gate_usage: Option<Account<'info, GateUsage>>,
+
+    /// Nonce record of the mint voucher, required when the campaign has a
+    /// voucher signer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoucherNonce::SIZE,
+        seeds = [
+            b"voucher",
+            campaign.key().as_ref(),
+            voucher.as_ref().map_or(0, |v| v.nonce).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub voucher_nonce: Option<Account<'info, VoucherNonce>>,
+
+    /// Campaign guard set, required when the campaign is guarded
+    #[account(
+        seeds = [b"guard_set", campaign.key().as_ref()],
+        bump
+    )]
+    pub guard_set: Option<Account<'info, GuardSet>>,
+
+    /// CHECK: Campaign guard program - verified against campaign.guard_program
+    #[account(executable)]
+    pub guard_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Post-mint hook program - verified against campaign.post_mint_hook
+    #[account(executable)]
+    pub post_mint_hook: Option<UncheckedAccount<'info>>,
+
+    /// Coupon redeemed by `coupon_code`
+    #[account(
+        mut,
+        seeds = [
+            b"coupon",
+            campaign.key().as_ref(),
+            coupon_code.as_deref().map(Coupon::code_hash).unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,
+
+    /// CHECK: Dutch auction escrow PDA for this mint, required when the mint
+    /// escrows part of the price - created in the handler once the sale gates pass
+    #[account(
+        mut,
+        seeds = [b"auction_receipt", campaign.key().as_ref(), nft_mint.key().as_ref()],
+        bump
+    )]
+    pub auction_receipt: Option<UncheckedAccount<'info>>,
+
     /// Affiliate stats PDA
     #[account(
         init_if_needed,
-        payer = buyer,
+        payer = payer,
         space = 8 + AffiliateStats::SIZE,
         seeds = [b"affiliate", campaign.key().as_ref(), affiliate_receiver.key().as_ref()],
         bump
@@ -102,163 +285,639 @@ pub struct ProcessMint<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     /// CHECK: Metaplex token metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn process_mint_instruction(
-        ctx: Context<ProcessMint>, 
+#[allow(clippy::too_many_arguments)]
+pub fn process_mint_instruction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessMint<'info>>, 
         affiliate_maybe: Option<Pubkey>, 
         name: String, 
         symbol: String, 
-        uri: String
+        uri: String,
+        allowlist: Option<AllowlistProof>,
+        voucher: Option<MintVoucher>,
+        coupon_code: Option<String>,
     ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        // 1) Supply check and sale gates; in bot tax mode a failed gate charges
+        // the buyer a penalty and returns without minting
+        require!(
+            campaign.asset_standard == AssetStandard::TokenMetadata
+                || campaign.asset_standard == AssetStandard::Programmable
+                || campaign.asset_standard == AssetStandard::PrintEdition,
+            ErrorCode::InvalidAssetStandard
+        );
+        check_affiliate(affiliate_maybe, ctx.accounts.affiliate_receiver.key())?;
+        let terms = match ctx.accounts.check_gates(
+            affiliate_maybe,
+            allowlist.as_ref(),
+            voucher.as_ref(),
+            ctx.remaining_accounts,
+        ) {
+            Ok(terms) => terms,
+            Err(error) if campaign.bot_tax > 0 => return ctx.accounts.charge_bot_tax(error),
+            Err(error) => return Err(error),
+        };
+        let SaleTerms { mut price, affiliate_fee_bps, phase_index, auction_price } = terms;
+
+        // 1i) Coupon discount, applied before the creator/affiliate split
+        if coupon_code.is_some() {
+            let coupon = ctx.accounts.coupon.as_mut().ok_or(ErrorCode::MissingCoupon)?;
+            price = coupon.apply(price)?;
+            coupon.uses = coupon.uses.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        // 2) Payment calculation & transfers. In Dutch auction rebate mode only
+        // the floor is split now and the rest is escrowed until settlement.
+        let escrowed = match campaign.pricing {
+            PricingMode::DutchAuction(auction) if auction.rebate && auction_price.is_some() => {
+                price.saturating_sub(auction.floor_price)
+            }
+            _ => 0,
+        };
+        let (creator_cut, affiliate_cut) = split_price(price - escrowed, affiliate_fee_bps)?;
+        // The affiliate's buyer rebate comes out of the affiliate cut only
+        let rebate = buyer_rebate(
+            &ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            affiliate_cut,
+        );
+        let affiliate_cut = affiliate_cut - rebate;
+        let price = price - rebate;
+        pay_creator_and_affiliate(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.affiliate_receiver.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            affiliate_maybe,
+            creator_cut,
+            affiliate_cut,
+        )?;
+
+        // 2a) Escrow the amount above the floor in the auction receipt
+        if escrowed > 0 {
+            let receipt_info = ctx
+                .accounts
+                .auction_receipt
+                .as_ref()
+                .ok_or(ErrorCode::MissingAuctionReceipt)?
+                .to_account_info();
+            let bump = ctx.bumps.auction_receipt.ok_or(ErrorCode::MissingAuctionReceipt)?;
+            let campaign_key = ctx.accounts.campaign.key();
+            let nft_mint_key = ctx.accounts.nft_mint.key();
+            let receipt_seeds: &[&[u8]] = &[b"auction_receipt", campaign_key.as_ref(), nft_mint_key.as_ref(), &[bump]];
+            create_pda_account(
+                &ctx.accounts.buyer.to_account_info(),
+                &receipt_info,
+                &ctx.accounts.system_program.to_account_info(),
+                8 + AuctionReceipt::SIZE,
+                &crate::ID,
+                receipt_seeds,
+            )?;
+            AuctionReceipt {
+                campaign: campaign_key,
+                nft_mint: nft_mint_key,
+                buyer: ctx.accounts.buyer.key(),
+                affiliate: affiliate_maybe
+                    .map(|_| ctx.accounts.affiliate_receiver.key())
+                    .filter(|receiver| *receiver != Pubkey::default()),
+                affiliate_fee_bps,
+                escrowed,
+                bump,
+            }
+            .try_serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])?;
+            transfer_lamports(
+                &ctx.accounts.buyer.to_account_info(),
+                &receipt_info,
+                &ctx.accounts.system_program.to_account_info(),
+                escrowed,
+            )?;
+        } else {
+            require!(ctx.accounts.auction_receipt.is_none(), ErrorCode::UnexpectedAuctionReceipt);
+        }
+
+        // 2b) Guard effects such as extra payments
+        if let Some(guard_set) = ctx.accounts.guard_set.as_ref().filter(|_| campaign.guarded) {
+            guard_set.apply(&ctx.accounts.guard_context()?, ctx.remaining_accounts)?;
+        }
+
+        // 3) Create the mint PDA and the owner's token account, which may be a
+        // recipient's. The payer's balance is tracked across steps 3-7 so only
+        // rent it actually paid is reimbursed.
+        let payer_lamports_before = ctx.accounts.payer.lamports();
+        let binding = ctx.accounts.campaign.key();
+        let index_bytes = ctx.accounts.campaign.minted.to_le_bytes();
+        let nft_mint_seeds: &[&[u8]] = &[b"nft_mint", binding.as_ref(), &index_bytes, &[ctx.bumps.nft_mint]];
+        create_pda_account(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Mint::LEN,
+            &ctx.accounts.token_program.key(),
+            nft_mint_seeds,
+        )?;
+        token::initialize_mint2(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                InitializeMint2 { mint: ctx.accounts.nft_mint.to_account_info() },
+            ),
+            0,
+            &ctx.accounts.mint_authority.key(),
+            Some(&ctx.accounts.mint_authority.key()),
+        )?;
+
+        associated_token::create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: ctx.accounts.buyer_ata.to_account_info(),
+                authority: ctx.accounts.nft_owner(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+
+        // 4-7) Mint, create metadata/edition and verify into collection
+        match campaign.asset_standard {
+            AssetStandard::Programmable => ctx.accounts.mint_programmable(name, symbol, uri)?,
+            AssetStandard::PrintEdition => ctx.accounts.mint_print_edition()?,
+            _ => {
+                ctx.accounts.master_edition_mint().mint(campaign, campaign.key(), name, symbol, uri)?;
+                ctx.accounts.collection_verification().verify(campaign, campaign.key())?;
+            }
+        }
+        let rent_paid = payer_lamports_before
+            .checked_sub(ctx.accounts.payer.lamports())
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // 8) Update affiliate stats if provided
+        record_affiliate_mint(
+            &mut ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            1,
+            affiliate_cut,
+        )?;
+
+        // 9) Increment campaign, wallet, allowlist and phase minted counts
         let campaign = &mut ctx.accounts.campaign;
+        campaign.minted = campaign.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        if let Some(auction_price) = auction_price {
+            campaign.clearing_price = auction_price;
+        }
+        if let Some(wallet_mints) = ctx.accounts.wallet_mints.as_mut() {
+            wallet_mints.minted = wallet_mints.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
+        if let Some(usage) = ctx.accounts.allowlist_usage.as_mut() {
+            usage.minted = usage.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
+        if let Some(voucher_nonce) = ctx.accounts.voucher_nonce.as_mut() {
+            voucher_nonce.used = true;
+        }
+        if campaign.gate_single_use {
+            if let Some(gate_usage) = ctx.accounts.gate_usage.as_mut() {
+                gate_usage.used = true;
+            }
+        }
+        if let (Some(index), Some(phases)) = (phase_index, ctx.accounts.campaign_phases.as_mut()) {
+            let phase = &mut phases.phases[index];
+            phase.minted = phase.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
 
-        // 1) Supply check
-        require!(campaign.minted < campaign.max_supply, ErrorCode::SoldOut);
-
-        // 2) Payment calculation & transfers
-        let price = campaign.price;
-        let affiliate_cut = ((price as u128) * (campaign.affiliate_fee_bps as u128) / 10_000u128) as u64;
-        let creator_cut = price.checked_sub(affiliate_cut).ok_or(ErrorCode::MathOverflow)?;
-
-        // Transfer creator_cut
-        if creator_cut > 0 {
-            invoke(
-                &system_instruction::transfer(&ctx.accounts.buyer.key(), &ctx.accounts.creator.key(), creator_cut),
-                &[
-                    ctx.accounts.buyer.to_account_info(),
-                    ctx.accounts.creator.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
+        // 9b) Notify the post-mint hook. A failure it reports aborts the mint
+        // when the hook is fatal and is logged otherwise.
+        if let Some(expected_hook) = ctx.accounts.campaign.post_mint_hook {
+            let hook = ctx.accounts.post_mint_hook.as_ref().ok_or(ErrorCode::MissingPostMintHook)?;
+            require_keys_eq!(hook.key(), expected_hook, ErrorCode::MissingPostMintHook);
+            let code = invoke_post_mint_hook(
+                &hook.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.campaign.to_account_info(),
+                affiliate_maybe,
+                price,
             )?;
+            if code != 0 {
+                require!(!ctx.accounts.campaign.post_mint_hook_fatal, ErrorCode::PostMintHookFailed);
+                msg!("Post-mint hook failed with code {}, ignored", code);
+            }
         }
 
-        // Transfer affiliate_cut if provided
-        if let Some(_affiliate_pk) = affiliate_maybe {
-            if affiliate_cut > 0 && ctx.accounts.affiliate_receiver.key() != Pubkey::default() {
-                invoke(
-                    &system_instruction::transfer(&ctx.accounts.buyer.key(), &ctx.accounts.affiliate_receiver.key(), affiliate_cut),
-                    &[
-                        ctx.accounts.buyer.to_account_info(),
-                        ctx.accounts.affiliate_receiver.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
+        // 10) Reimburse the rent the payer spent creating this mint's accounts.
+        // Lamports prefunded into those addresses were not paid by the payer
+        // and are not counted.
+        if ctx.accounts.campaign.rent_sponsored {
+            if let Some(rent_sponsor) = ctx.accounts.rent_sponsor.as_ref() {
+                reimburse_rent(
+                    &ctx.accounts.campaign,
+                    &rent_sponsor.to_account_info(),
+                    &ctx.accounts.payer.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    rent_paid,
                 )?;
             }
-        } else {
-            // If no affiliate, send affiliate_cut to creator
-            if affiliate_cut > 0 {
-                invoke(
-                    &system_instruction::transfer(&ctx.accounts.buyer.key(), &ctx.accounts.creator.key(), affiliate_cut),
-                    &[
-                        ctx.accounts.buyer.to_account_info(),
-                        ctx.accounts.creator.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
-                )?;
+        }
+
+        // 11) Emit the mint receipt
+        emit!(MintReceipt {
+            campaign: ctx.accounts.campaign.key(),
+            nft_mint: ctx.accounts.nft_mint.key(),
+            buyer: ctx.accounts.buyer.key(),
+            payer: ctx.accounts.payer.key(),
+            recipient: ctx.accounts.nft_owner().key(),
+            affiliate: affiliate_maybe,
+            price,
+        });
+
+        Ok(())
+    }
+
+/// Campaign master mint PDA that print editions are printed from.
+fn print_master_mint(campaign: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"master_mint", campaign.as_ref()], &crate::ID).0
+}
+
+/// Price and commission resolved by the sale gates.
+struct SaleTerms {
+    price: u64,
+    affiliate_fee_bps: u16,
+    phase_index: Option<usize>,
+    /// Dutch auction price, when the mint is sold at it
+    auction_price: Option<u64>,
+}
+
+impl<'info> ProcessMint<'info> {
+    /// Runs the supply check and every configured sale gate, returning the
+    /// price and commission that apply to this mint.
+    fn check_gates(
+        &self,
+        affiliate_maybe: Option<Pubkey>,
+        allowlist: Option<&AllowlistProof>,
+        voucher: Option<&MintVoucher>,
+        guard_accounts: &[AccountInfo<'info>],
+    ) -> Result<SaleTerms> {
+        let campaign = &self.campaign;
+        campaign.check_supply(1)?;
+
+        // 1a) Backend cosignature
+        if let Some(required_cosigner) = campaign.required_cosigner {
+            let cosigner = self.cosigner.as_ref().ok_or(ErrorCode::MissingCosigner)?;
+            require_keys_eq!(cosigner.key(), required_cosigner, ErrorCode::MissingCosigner);
+        }
+
+        // 1b) Per-wallet limit, counted against the NFT owner
+        if campaign.max_per_wallet > 0 {
+            let wallet_mints = self.wallet_mints.as_ref().ok_or(ErrorCode::MissingWalletCounter)?;
+            require!(wallet_mints.minted < campaign.max_per_wallet, ErrorCode::WalletLimitReached);
+        }
+
+        // 1c) Token gate: the buyer must hold a verified NFT of the gate collection
+        if let Some(gate_collection) = campaign.gate_collection {
+            let (Some(gate_token_account), Some(gate_metadata)) = (
+                self.gate_token_account.as_ref(),
+                self.gate_metadata.as_ref(),
+            ) else {
+                return err!(ErrorCode::TokenGateRequired);
+            };
+            verify_gate_holding(
+                &self.buyer.key(),
+                gate_token_account,
+                &gate_metadata.to_account_info(),
+                &gate_collection,
+            )?;
+            if campaign.gate_single_use {
+                let gate_usage = self.gate_usage.as_ref().ok_or(ErrorCode::TokenGateRequired)?;
+                require!(!gate_usage.used, ErrorCode::GateTokenUsed);
             }
         }
 
-        // 3) Use Anchor's built-in mint initialization instead of manual creation
-        // The mint is already initialized via the account constraints
+        // 1d) Resolve the active phase, which sets price, commission and gating;
+        // otherwise the list price (fixed, Dutch auction or bonding curve) applies
+        let now = Clock::get()?.unix_timestamp;
+        let mut price = campaign.list_price(now)?;
+        let mut auction_price = match campaign.pricing {
+            PricingMode::DutchAuction(_) => Some(price),
+            _ => None,
+        };
+        let mut affiliate_fee_bps = campaign.affiliate_fee_bps;
+        let mut allowlist_root = campaign.allowlist_root;
+        let mut phase_index = None;
+        if campaign.phased {
+            let phases = self.campaign_phases.as_ref().ok_or(ErrorCode::MissingPhasesAccount)?;
+            let index = phases.active_phase(now)?;
+            let phase = &phases.phases[index];
+            require!(phase.max_mints == 0 || phase.minted < phase.max_mints, ErrorCode::PhaseSoldOut);
+            price = phase.price;
+            auction_price = None;
+            affiliate_fee_bps = phase.affiliate_fee_bps;
+            allowlist_root = phase.allowlist_root;
+            phase_index = Some(index);
+        }
 
-        // 4) Mint token to buyer
-        let mint_auth_bump = campaign.mint_authority_bump;
-        let binding = campaign.key();
-        let seeds_for_mint_auth: &[&[u8]] = &[b"mint_auth", binding.as_ref(), &[mint_auth_bump]];
-        let signer_seeds_mint_auth = &[seeds_for_mint_auth];
+        // 1e) Allowlist proof and leaf allocation; the leaf may set its own price
+        if let Some(root) = allowlist_root {
+            let entry = allowlist.ok_or(ErrorCode::AllowlistProofRequired)?;
+            require!(entry.verify(&self.buyer.key(), &root), ErrorCode::InvalidAllowlistProof);
+            let usage = self.allowlist_usage.as_ref().ok_or(ErrorCode::AllowlistProofRequired)?;
+            require!(usage.minted < entry.max_mints, ErrorCode::AllowlistAllocationUsed);
+            if let Some(entry_price) = entry.price {
+                price = entry_price;
+                auction_price = None;
+            }
+        }
+
+        // 1f) Signed voucher from the campaign voucher signer; sets the final price
+        if let Some(voucher_signer) = campaign.voucher_signer {
+            let voucher = voucher.ok_or(ErrorCode::VoucherRequired)?;
+            let (Some(instructions_sysvar), Some(voucher_nonce)) = (
+                self.sysvar_instructions.as_ref(),
+                self.voucher_nonce.as_ref(),
+            ) else {
+                return err!(ErrorCode::VoucherRequired);
+            };
+            require_keys_eq!(voucher.buyer, self.buyer.key(), ErrorCode::InvalidVoucher);
+            require!(voucher.affiliate == affiliate_maybe, ErrorCode::InvalidVoucher);
+            require!(now < voucher.expiry, ErrorCode::VoucherExpired);
+            require!(!voucher_nonce.used, ErrorCode::VoucherUsed);
+            verify_ed25519_instruction(
+                &instructions_sysvar.to_account_info(),
+                &voucher_signer,
+                &voucher.message(&campaign.key())?,
+            )?;
+            price = voucher.price;
+            auction_price = None;
+        }
+
+        // 1g) Campaign guard set, reading its accounts from remaining accounts
+        if campaign.guarded {
+            let guard_set = self.guard_set.as_ref().ok_or(ErrorCode::MissingGuardSet)?;
+            guard_set.validate(&self.guard_context()?, guard_accounts)?;
+        }
+
+        // 1h) External guard program; its errors abort the transaction, so
+        // they are never bot taxed
+        if let Some(expected_program) = campaign.guard_program {
+            let guard_program = self.guard_program.as_ref().ok_or(ErrorCode::MissingGuardProgram)?;
+            require_keys_eq!(guard_program.key(), expected_program, ErrorCode::MissingGuardProgram);
+            invoke_guard_program(
+                &guard_program.to_account_info(),
+                &self.buyer.to_account_info(),
+                &self.campaign.to_account_info(),
+                affiliate_maybe,
+                1,
+            )?;
+        }
 
-        let cpi_accounts_mint_to = MintTo {
-            mint: ctx.accounts.nft_mint.to_account_info(),
-            to: ctx.accounts.buyer_ata.to_account_info(),
-            authority: ctx.accounts.mint_authority.to_account_info(),
+        Ok(SaleTerms { price, affiliate_fee_bps, phase_index, auction_price })
+
+    }
+
+    fn guard_context(&self) -> Result<GuardContext<'_, 'info>> {
+        Ok(GuardContext {
+            buyer: self.buyer.as_ref(),
+            system_program: self.system_program.as_ref(),
+            now: Clock::get()?.unix_timestamp,
+        })
+    }
+
+    /// Bot tax path: moves the campaign penalty from the buyer to the creator
+    /// and reports the failed gate instead of reverting.
+    fn charge_bot_tax(&self, error: Error) -> Result<()> {
+        let lamports = self.campaign.bot_tax;
+        transfer_lamports(
+            &self.buyer.to_account_info(),
+            &self.creator.to_account_info(),
+            &self.system_program.to_account_info(),
+            lamports,
+        )?;
+
+        let error_code = match &error {
+            Error::AnchorError(e) => e.error_code_number,
+            Error::ProgramError(_) => 0,
         };
-        let cpi_program_mint = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx_mint = CpiContext::new_with_signer(cpi_program_mint, cpi_accounts_mint_to, signer_seeds_mint_auth);
-        token::mint_to(cpi_ctx_mint, 1)?;
-
-        // 5) Create metadata via Metaplex CPI
-        let data_v2 = DataV2 {
-            name: name.clone(),
-            symbol: symbol.clone(),
-            uri: uri.clone(),
-            seller_fee_basis_points: 0,
-            creators: None,
-            collection: None,
-            uses: None,
+        msg!("Bot tax charged: {}", error);
+        emit!(BotTaxCharged {
+            campaign: self.campaign.key(),
+            buyer: self.buyer.key(),
+            lamports,
+            error_code,
+        });
+
+        Ok(())
+    }
+
+    /// Wallet receiving the NFT: the gift recipient if given, else the buyer.
+    fn nft_owner(&self) -> AccountInfo<'info> {
+        match &self.recipient {
+            Some(recipient) => recipient.to_account_info(),
+            None => self.buyer.to_account_info(),
+        }
+    }
+
+    /// Accounts for the default master edition path.
+    fn master_edition_mint(&self) -> MasterEditionMint<'info> {
+        MasterEditionMint {
+            payer: self.payer.to_account_info(),
+            nft_mint: self.nft_mint.to_account_info(),
+            token_account: self.buyer_ata.to_account_info(),
+            mint_authority: self.mint_authority.to_account_info(),
+            metadata: self.metadata.to_account_info(),
+            master_edition: self.master_edition.to_account_info(),
+            collection_mint: self.collection_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        }
+    }
+
+    /// Accounts for the sized collection verification step.
+    fn collection_verification(&self) -> CollectionVerification<'info> {
+        CollectionVerification {
+            payer: self.payer.to_account_info(),
+            metadata: self.metadata.to_account_info(),
+            collection_mint: self.collection_mint.to_account_info(),
+            collection_metadata: self.collection_metadata.to_account_info(),
+            collection_master_edition: self.collection_master_edition.to_account_info(),
+            collection_authority: self.collection_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        }
+    }
+
+    /// Print edition path: mint the token, then print the next numbered edition
+    /// of the program-held master onto it.
+    fn mint_print_edition(&self) -> Result<()> {
+        let (
+            Some(print_master_token_account),
+            Some(print_master_metadata),
+            Some(print_master_edition),
+            Some(edition_marker),
+        ) = (
+            self.print_master_token_account.as_ref(),
+            self.print_master_metadata.as_ref(),
+            self.print_master_edition.as_ref(),
+            self.edition_marker.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingPrintAccounts);
         };
 
-        CreateMetadataAccountV3Cpi::new(
-            &ctx.accounts.token_metadata_program.to_account_info(),
-            CreateMetadataAccountV3CpiAccounts {
-                metadata: &ctx.accounts.metadata.to_account_info(),
-                mint: &ctx.accounts.nft_mint.to_account_info(),
-                mint_authority: &ctx.accounts.mint_authority.to_account_info(),
-                payer: &ctx.accounts.buyer.to_account_info(),
-                update_authority: (&ctx.accounts.mint_authority.to_account_info(), true),
-                system_program: &ctx.accounts.system_program.to_account_info(),
-                rent: Some(&ctx.accounts.rent.to_account_info()),
+        let binding = self.campaign.key();
+        let seeds_for_mint_auth: &[&[u8]] = &[b"mint_auth", binding.as_ref(), &[self.campaign.mint_authority_bump]];
+        let signer_seeds_mint_auth = &[seeds_for_mint_auth];
+
+        // 4) Mint token to the owner
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.nft_mint.to_account_info(),
+                    to: self.buyer_ata.to_account_info(),
+                    authority: self.mint_authority.to_account_info(),
+                },
+                signer_seeds_mint_auth,
+            ),
+            1,
+        )?;
+
+        // 5-6) Print the next edition; numbering starts at 1
+        let edition = self.campaign.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        MintNewEditionFromMasterEditionViaTokenCpi::new(
+            &self.token_metadata_program.to_account_info(),
+            MintNewEditionFromMasterEditionViaTokenCpiAccounts {
+                new_metadata: &self.metadata.to_account_info(),
+                new_edition: &self.master_edition.to_account_info(),
+                master_edition: &print_master_edition.to_account_info(),
+                new_mint: &self.nft_mint.to_account_info(),
+                edition_mark_pda: &edition_marker.to_account_info(),
+                new_mint_authority: &self.mint_authority.to_account_info(),
+                payer: &self.payer.to_account_info(),
+                token_account_owner: &self.mint_authority.to_account_info(),
+                token_account: &print_master_token_account.to_account_info(),
+                new_metadata_update_authority: &self.mint_authority.to_account_info(),
+                metadata: &print_master_metadata.to_account_info(),
+                token_program: &self.token_program.to_account_info(),
+                system_program: &self.system_program.to_account_info(),
+                rent: Some(&self.rent.to_account_info()),
+            },
+            MintNewEditionFromMasterEditionViaTokenInstructionArgs {
+                mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs {
+                    edition,
+                },
             },
-            CreateMetadataAccountV3InstructionArgs {
-                data: data_v2,
+        )
+        .invoke_signed(signer_seeds_mint_auth)?;
+
+        // 7) Verify minted item into collection
+        self.collection_verification().verify(&self.campaign, binding)
+    }
+
+    /// Programmable NFT path: Token Metadata `Create`/`Mint` with an optional
+    /// rule set, verified into the collection with `Verify`.
+    fn mint_programmable(&self, name: String, symbol: String, uri: String) -> Result<()> {
+        let (Some(token_record), Some(sysvar_instructions)) =
+            (self.token_record.as_ref(), self.sysvar_instructions.as_ref())
+        else {
+            return err!(ErrorCode::MissingProgrammableAccounts);
+        };
+
+        // The rule set passed in must be the one the campaign was configured with
+        let rule_set = self.campaign.rule_set;
+        let authorization_rules = match rule_set {
+            Some(rule_set) => {
+                let rules = self.authorization_rules.as_ref().ok_or(ErrorCode::MissingProgrammableAccounts)?;
+                require_keys_eq!(rules.key(), rule_set, ErrorCode::InvalidRuleSet);
+                require!(self.authorization_rules_program.is_some(), ErrorCode::MissingProgrammableAccounts);
+                Some(rules.to_account_info())
+            }
+            None => None,
+        };
+        let authorization_rules_program = self.authorization_rules_program.as_ref().map(|p| p.to_account_info());
+
+        let binding = self.campaign.key();
+        let seeds_for_mint_auth: &[&[u8]] = &[b"mint_auth", binding.as_ref(), &[self.campaign.mint_authority_bump]];
+        let coll_auth_seeds: &[&[u8]] = &[b"collection_auth", binding.as_ref(), &[self.campaign.collection_auth_bump]];
+
+        // 4) Create metadata and master edition for the existing mint
+        CreateV1Cpi::new(
+            &self.token_metadata_program.to_account_info(),
+            CreateV1CpiAccounts {
+                metadata: &self.metadata.to_account_info(),
+                master_edition: Some(&self.master_edition.to_account_info()),
+                mint: (&self.nft_mint.to_account_info(), false),
+                authority: &self.mint_authority.to_account_info(),
+                payer: &self.payer.to_account_info(),
+                update_authority: (&self.mint_authority.to_account_info(), true),
+                system_program: &self.system_program.to_account_info(),
+                sysvar_instructions: &sysvar_instructions.to_account_info(),
+                spl_token_program: Some(&self.token_program.to_account_info()),
+            },
+            CreateV1InstructionArgs {
+                name,
+                symbol,
+                uri,
+                seller_fee_basis_points: 0,
+                creators: None,
+                primary_sale_happened: false,
                 is_mutable: true,
+                token_standard: TokenStandard::ProgrammableNonFungible,
+                collection: Some(Collection { verified: false, key: self.collection_mint.key() }),
+                uses: None,
                 collection_details: None,
+                rule_set,
+                decimals: Some(0),
+                print_supply: Some(PrintSupply::Zero),
             },
         )
-        .invoke_signed(&[&[b"mint_auth", campaign.key().as_ref(), &[mint_auth_bump]]])?;
-
-        // 6) Create master edition
-        CreateMasterEditionV3Cpi::new(
-            &ctx.accounts.token_metadata_program.to_account_info(),
-            CreateMasterEditionV3CpiAccounts {
-                edition: &ctx.accounts.master_edition.to_account_info(),
-                mint: &ctx.accounts.nft_mint.to_account_info(),
-                update_authority: &ctx.accounts.mint_authority.to_account_info(),
-                mint_authority: &ctx.accounts.mint_authority.to_account_info(),
-                payer: &ctx.accounts.buyer.to_account_info(),
-                metadata: &ctx.accounts.metadata.to_account_info(),
-                token_program: &ctx.accounts.token_program.to_account_info(),
-                system_program: &ctx.accounts.system_program.to_account_info(),
-                rent: Some(&ctx.accounts.rent.to_account_info()),
+        .invoke_signed(&[seeds_for_mint_auth])?;
+
+        // 5) Mint the token to the owner, creating its token record
+        MintV1Cpi::new(
+            &self.token_metadata_program.to_account_info(),
+            MintV1CpiAccounts {
+                token: &self.buyer_ata.to_account_info(),
+                token_owner: Some(&self.nft_owner()),
+                metadata: &self.metadata.to_account_info(),
+                master_edition: Some(&self.master_edition.to_account_info()),
+                token_record: Some(&token_record.to_account_info()),
+                mint: &self.nft_mint.to_account_info(),
+                authority: &self.mint_authority.to_account_info(),
+                delegate_record: None,
+                payer: &self.payer.to_account_info(),
+                system_program: &self.system_program.to_account_info(),
+                sysvar_instructions: &sysvar_instructions.to_account_info(),
+                spl_token_program: &self.token_program.to_account_info(),
+                spl_ata_program: &self.associated_token_program.to_account_info(),
+                authorization_rules_program: authorization_rules_program.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
             },
-            CreateMasterEditionV3InstructionArgs { max_supply: Some(0) },
+            MintV1InstructionArgs { amount: 1, authorization_data: None },
         )
-        .invoke_signed(&[&[b"mint_auth", campaign.key().as_ref(), &[mint_auth_bump]]])?;
-
-        // 7) Verify minted item into collection
-        let coll_auth_bump = campaign.collection_auth_bump;
-        let coll_auth_seeds: &[&[u8]] = &[b"collection_auth", campaign.to_account_info().key.as_ref(), &[coll_auth_bump]];
-        let signer_seeds_collection_auth = &[coll_auth_seeds];
-
-        VerifySizedCollectionItemCpi::new(
-            &ctx.accounts.token_metadata_program.to_account_info(),
-            VerifySizedCollectionItemCpiAccounts {
-                metadata: &ctx.accounts.metadata.to_account_info(),
-                collection_authority: &ctx.accounts.collection_authority.to_account_info(),
-                payer: &ctx.accounts.buyer.to_account_info(),
-                collection_mint: &ctx.accounts.collection_mint.to_account_info(),
-                collection: &ctx.accounts.collection_metadata.to_account_info(),
-                collection_master_edition_account: &ctx.accounts.collection_master_edition.to_account_info(),
-                collection_authority_record: None,
+        .invoke_signed(&[seeds_for_mint_auth])?;
+
+        // 6) Verify minted item into collection
+        VerifyCollectionV1Cpi::new(
+            &self.token_metadata_program.to_account_info(),
+            VerifyCollectionV1CpiAccounts {
+                authority: &self.collection_authority.to_account_info(),
+                delegate_record: None,
+                metadata: &self.metadata.to_account_info(),
+                collection_mint: &self.collection_mint.to_account_info(),
+                collection_metadata: Some(&self.collection_metadata.to_account_info()),
+                collection_master_edition: Some(&self.collection_master_edition.to_account_info()),
+                system_program: &self.system_program.to_account_info(),
+                sysvar_instructions: &sysvar_instructions.to_account_info(),
             },
         )
-        .invoke_signed(signer_seeds_collection_auth)?;
-
-        // 8) Update affiliate stats if provided
-        if let Some(_affiliate_pk) = affiliate_maybe {
-            if ctx.accounts.affiliate_receiver.key() != Pubkey::default() {
-                let stats = &mut ctx.accounts.affiliate_stats;
-                stats.total_mints = stats.total_mints.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
-                stats.total_earned = stats.total_earned.checked_add(affiliate_cut).ok_or(ErrorCode::MathOverflow)?;
-            }
-        }
-
-        // 9) Increment campaign minted count
-        campaign.minted = campaign.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        .invoke_signed(&[coll_auth_seeds])?;
 
         Ok(())
     }
-
+}
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token::{self, InitializeMint2, Mint, Token},
+};
+use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
+
+use super::guard_program::invoke_guard_program;
+use super::master_edition::{CollectionVerification, MasterEditionMint};
+use super::payment::{
+    buyer_rebate, check_affiliate, create_pda_account, pay_creator_and_affiliate, record_affiliate_mint, split_price,
+};
+use crate::state::{AffiliateStats, AssetStandard, Campaign};
+use crate::error::ErrorCode;
+
+/// Remaining accounts per item: nft_mint PDA, buyer ATA, metadata, master edition.
+pub const ACCOUNTS_PER_BATCH_ITEM: usize = 4;
+
+#[derive(Accounts)]
+#[instruction(affiliate_maybe: Option<Pubkey>, quantity: u64, name: String, symbol: String, uri: String)]
+pub struct ProcessMintBatch<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut, has_one = collection_mint)]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// CHECK: Creator account verified via campaign.creator constraint
+    #[account(mut, address = campaign.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: Affiliate receiver account - can be any account
+    #[account(mut)]
+    pub affiliate_receiver: UncheckedAccount<'info>,
+
+    /// CHECK: Mint authority PDA - verified by seeds constraint
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Collection mint - verified via campaign constraint
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Collection metadata account
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition account
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Collection authority PDA - verified by seeds constraint
+    #[account(
+        seeds = [b"collection_auth", campaign.key().as_ref()],
+        bump = campaign.collection_auth_bump
+    )]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    /// Affiliate stats PDA
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + AffiliateStats::SIZE,
+        seeds = [b"affiliate", campaign.key().as_ref(), affiliate_receiver.key().as_ref()],
+        bump
+    )]
+    pub affiliate_stats: Box<Account<'info, AffiliateStats>>,
+
+    /// CHECK: Campaign guard program - verified against campaign.guard_program
+    #[account(executable)]
+    pub guard_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Buyer mints `quantity` NFTs in one instruction. Payment is split and paid
+/// once for `price * quantity`; each item's accounts come from remaining
+/// accounts in `ACCOUNTS_PER_BATCH_ITEM` groups. The campaign guard program,
+/// if any, is called once with the whole `quantity`.
+pub fn process_mint_batch_instruction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessMintBatch<'info>>,
+        affiliate_maybe: Option<Pubkey>,
+        quantity: u64,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        // 1) Supply check
+        require!(campaign.asset_standard == AssetStandard::TokenMetadata, ErrorCode::InvalidAssetStandard);
+        require!(quantity > 0, ErrorCode::InvalidQuantity);
+        campaign.check_batch_mintable(quantity)?;
+        check_affiliate(affiliate_maybe, ctx.accounts.affiliate_receiver.key())?;
+
+        // 1a) External guard program, asked about the whole batch
+        if let Some(expected_program) = campaign.guard_program {
+            let guard_program = ctx.accounts.guard_program.as_ref().ok_or(ErrorCode::MissingGuardProgram)?;
+            require_keys_eq!(guard_program.key(), expected_program, ErrorCode::MissingGuardProgram);
+            invoke_guard_program(
+                &guard_program.to_account_info(),
+                &ctx.accounts.buyer.to_account_info(),
+                &campaign.to_account_info(),
+                affiliate_maybe,
+                quantity,
+            )?;
+        }
+
+        let item_accounts = (quantity as usize)
+            .checked_mul(ACCOUNTS_PER_BATCH_ITEM)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(ctx.remaining_accounts.len() == item_accounts, ErrorCode::InvalidRemainingAccounts);
+
+        // 2) Payment calculation & transfers, once for the whole batch
+        let total_price = campaign.price.checked_mul(quantity).ok_or(ErrorCode::MathOverflow)?;
+        let (creator_cut, affiliate_cut) = split_price(total_price, campaign.affiliate_fee_bps)?;
+        // The affiliate's buyer rebate comes out of the affiliate cut only
+        let rebate = buyer_rebate(
+            &ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            affiliate_cut,
+        );
+        let affiliate_cut = affiliate_cut - rebate;
+        pay_creator_and_affiliate(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.affiliate_receiver.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            affiliate_maybe,
+            creator_cut,
+            affiliate_cut,
+        )?;
+
+        // 3-7) Create, mint and verify each item
+        let first_index = campaign.minted;
+        for (i, item) in ctx.remaining_accounts.chunks(ACCOUNTS_PER_BATCH_ITEM).enumerate() {
+            let index = first_index.checked_add(i as u64).ok_or(ErrorCode::MathOverflow)?;
+            ctx.accounts.mint_item(index, item, name.clone(), symbol.clone(), uri.clone())?;
+        }
+
+        // 8) Update affiliate stats if provided
+        record_affiliate_mint(
+            &mut ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            quantity,
+            affiliate_cut,
+        )?;
+
+        // 9) Increment campaign minted count
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.minted = campaign.minted.checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+
+impl<'info> ProcessMintBatch<'info> {
+    /// Mints item `index` using its [nft_mint, buyer_ata, metadata, master_edition]
+    /// accounts, mirroring the single `process_mint` path.
+    fn mint_item(
+        &self,
+        index: u64,
+        item: &[AccountInfo<'info>],
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        let [nft_mint, buyer_ata, metadata, master_edition] = item else {
+            return err!(ErrorCode::InvalidRemainingAccounts);
+        };
+
+        let binding = self.campaign.key();
+        let index_bytes = index.to_le_bytes();
+        let (expected_mint, mint_bump) =
+            Pubkey::find_program_address(&[b"nft_mint", binding.as_ref(), &index_bytes], &crate::ID);
+        require_keys_eq!(nft_mint.key(), expected_mint, ErrorCode::InvalidMintAccount);
+
+        let nft_mint_seeds: &[&[u8]] = &[b"nft_mint", binding.as_ref(), &index_bytes, &[mint_bump]];
+
+        // 3) Create the mint PDA
+        create_pda_account(
+            &self.buyer.to_account_info(),
+            nft_mint,
+            &self.system_program.to_account_info(),
+            Mint::LEN,
+            &self.token_program.key(),
+            nft_mint_seeds,
+        )?;
+
+        token::initialize_mint2(
+            CpiContext::new(self.token_program.to_account_info(), InitializeMint2 { mint: nft_mint.clone() }),
+            0,
+            &self.mint_authority.key(),
+            Some(&self.mint_authority.key()),
+        )?;
+
+        // 3b) Create the buyer's token account
+        associated_token::create(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            Create {
+                payer: self.buyer.to_account_info(),
+                associated_token: buyer_ata.clone(),
+                authority: self.buyer.to_account_info(),
+                mint: nft_mint.clone(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+
+        // 4-6) Mint and create metadata/edition
+        MasterEditionMint {
+            payer: self.buyer.to_account_info(),
+            nft_mint: nft_mint.clone(),
+            token_account: buyer_ata.clone(),
+            mint_authority: self.mint_authority.to_account_info(),
+            metadata: metadata.clone(),
+            master_edition: master_edition.clone(),
+            collection_mint: self.collection_mint.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        }
+        .mint(&self.campaign, binding, name, symbol, uri)?;
+
+        // 7) Verify minted item into collection
+        CollectionVerification {
+            payer: self.buyer.to_account_info(),
+            metadata: metadata.clone(),
+            collection_mint: self.collection_mint.to_account_info(),
+            collection_metadata: self.collection_metadata.to_account_info(),
+            collection_master_edition: self.collection_master_edition.to_account_info(),
+            collection_authority: self.collection_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+        }
+        .verify(&self.campaign, binding)
+    }
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::instructions::{
+    MintToCollectionV1Cpi, MintToCollectionV1CpiAccounts, MintToCollectionV1InstructionArgs,
+};
+use mpl_bubblegum::types::{Collection, MetadataArgs, TokenProgramVersion, TokenStandard};
+use mpl_bubblegum::ID as MPL_BUBBLEGUM_ID;
+use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
+
+use super::payment::{buyer_rebate, pay_creator_and_affiliate, record_affiliate_mint, split_price};
+use crate::constants::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::state::{AffiliateStats, AssetStandard, Campaign};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(affiliate_maybe: Option<Pubkey>, name: String, symbol: String, uri: String)]
+pub struct ProcessMintCompressed<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut, has_one = collection_mint, has_one = merkle_tree)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Creator account verified via campaign.creator constraint
+    #[account(mut, address = campaign.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: Affiliate receiver account - can be any account
+    #[account(mut)]
+    pub affiliate_receiver: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum tree config PDA - verified by Bubblegum
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Campaign merkle tree - verified via campaign constraint
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Mint authority PDA - tree creator, verified by seeds constraint
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Collection mint - verified via campaign constraint
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Collection metadata account
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition account
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Collection authority PDA - verified by seeds constraint
+    #[account(
+        seeds = [b"collection_auth", campaign.key().as_ref()],
+        bump = campaign.collection_auth_bump
+    )]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum collection CPI signer PDA - verified by Bubblegum
+    pub bubblegum_signer: UncheckedAccount<'info>,
+
+    /// Affiliate stats PDA
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + AffiliateStats::SIZE,
+        seeds = [b"affiliate", campaign.key().as_ref(), affiliate_receiver.key().as_ref()],
+        bump
+    )]
+    pub affiliate_stats: Account<'info, AffiliateStats>,
+
+    /// CHECK: Bubblegum program
+    #[account(address = MPL_BUBBLEGUM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buyer mints a compressed NFT into the campaign merkle tree, verified into
+/// the collection by Bubblegum.
+pub fn process_mint_compressed_instruction(
+        ctx: Context<ProcessMintCompressed>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // 1) Supply check
+        require!(campaign.asset_standard == AssetStandard::Compressed, ErrorCode::InvalidAssetStandard);
+        campaign.check_mintable(1)?;
+
+        // 2) Payment calculation & transfers
+        let (creator_cut, affiliate_cut) = split_price(campaign.price, campaign.affiliate_fee_bps)?;
+        // The affiliate's buyer rebate comes out of the affiliate cut only
+        let rebate = buyer_rebate(
+            &ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            affiliate_cut,
+        );
+        let affiliate_cut = affiliate_cut - rebate;
+        pay_creator_and_affiliate(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.affiliate_receiver.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            affiliate_maybe,
+            creator_cut,
+            affiliate_cut,
+        )?;
+
+        // 3) Mint the leaf to the buyer and verify it into the collection
+        let campaign_key = campaign.key();
+        let mint_auth_seeds: &[&[u8]] = &[b"mint_auth", campaign_key.as_ref(), &[campaign.mint_authority_bump]];
+        let coll_auth_seeds: &[&[u8]] = &[b"collection_auth", campaign_key.as_ref(), &[campaign.collection_auth_bump]];
+
+        let metadata = MetadataArgs {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection: Some(Collection { verified: false, key: campaign.collection_mint }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![],
+        };
+
+        MintToCollectionV1Cpi::new(
+            &ctx.accounts.bubblegum_program.to_account_info(),
+            MintToCollectionV1CpiAccounts {
+                tree_config: &ctx.accounts.tree_config.to_account_info(),
+                leaf_owner: &ctx.accounts.buyer.to_account_info(),
+                leaf_delegate: &ctx.accounts.buyer.to_account_info(),
+                merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+                payer: &ctx.accounts.buyer.to_account_info(),
+                tree_creator_or_delegate: &ctx.accounts.mint_authority.to_account_info(),
+                collection_authority: &ctx.accounts.collection_authority.to_account_info(),
+                collection_authority_record_pda: None,
+                collection_mint: &ctx.accounts.collection_mint.to_account_info(),
+                collection_metadata: &ctx.accounts.collection_metadata.to_account_info(),
+                collection_edition: &ctx.accounts.collection_master_edition.to_account_info(),
+                bubblegum_signer: &ctx.accounts.bubblegum_signer.to_account_info(),
+                log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+                token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+            },
+            MintToCollectionV1InstructionArgs { metadata },
+        )
+        .invoke_signed(&[mint_auth_seeds, coll_auth_seeds])?;
+
+        // 4) Update affiliate stats if provided
+        record_affiliate_mint(
+            &mut ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            1,
+            affiliate_cut,
+        )?;
+
+        // 5) Increment campaign minted count
+        campaign.minted = campaign.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
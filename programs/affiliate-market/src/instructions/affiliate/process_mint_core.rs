@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::{CreateV2Cpi, CreateV2CpiAccounts, CreateV2InstructionArgs};
+use mpl_core::types::DataState;
+use mpl_core::ID as MPL_CORE_ID;
+
+use super::payment::{buyer_rebate, pay_creator_and_affiliate, record_affiliate_mint, split_price};
+use crate::state::{AffiliateStats, AssetStandard, Campaign};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(affiliate_maybe: Option<Pubkey>, name: String, uri: String)]
+pub struct ProcessMintCore<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Creator account verified via campaign.creator constraint
+    #[account(mut, address = campaign.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: Affiliate receiver account - can be any account
+    #[account(mut)]
+    pub affiliate_receiver: UncheckedAccount<'info>,
+
+    /// CHECK: Core asset PDA - created by the Core CPI
+    #[account(
+        mut,
+        seeds = [b"asset", campaign.key().as_ref(), &campaign.minted.to_le_bytes()],
+        bump
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Core collection - verified against campaign.collection_mint
+    #[account(mut, address = campaign.collection_mint)]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Collection authority PDA - update authority of the Core collection
+    #[account(
+        seeds = [b"collection_auth", campaign.key().as_ref()],
+        bump = campaign.collection_auth_bump
+    )]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    /// Affiliate stats PDA
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + AffiliateStats::SIZE,
+        seeds = [b"affiliate", campaign.key().as_ref(), affiliate_receiver.key().as_ref()],
+        bump
+    )]
+    pub affiliate_stats: Account<'info, AffiliateStats>,
+
+    /// CHECK: Metaplex Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buyer mints a Metaplex Core asset into the campaign's Core collection.
+pub fn process_mint_core_instruction(
+        ctx: Context<ProcessMintCore>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        uri: String
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // 1) Supply check
+        require!(campaign.asset_standard == AssetStandard::Core, ErrorCode::InvalidAssetStandard);
+        campaign.check_mintable(1)?;
+
+        // 2) Payment calculation & transfers
+        let (creator_cut, affiliate_cut) = split_price(campaign.price, campaign.affiliate_fee_bps)?;
+        // The affiliate's buyer rebate comes out of the affiliate cut only
+        let rebate = buyer_rebate(
+            &ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            affiliate_cut,
+        );
+        let affiliate_cut = affiliate_cut - rebate;
+        pay_creator_and_affiliate(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.affiliate_receiver.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            affiliate_maybe,
+            creator_cut,
+            affiliate_cut,
+        )?;
+
+        // 3) Create the asset in the collection, owned by the buyer
+        let campaign_key = campaign.key();
+        let minted_bytes = campaign.minted.to_le_bytes();
+        let asset_seeds: &[&[u8]] = &[b"asset", campaign_key.as_ref(), &minted_bytes, &[ctx.bumps.asset]];
+        let coll_auth_seeds: &[&[u8]] = &[b"collection_auth", campaign_key.as_ref(), &[campaign.collection_auth_bump]];
+
+        CreateV2Cpi::new(
+            &ctx.accounts.mpl_core_program.to_account_info(),
+            CreateV2CpiAccounts {
+                asset: &ctx.accounts.asset.to_account_info(),
+                collection: Some(&ctx.accounts.collection.to_account_info()),
+                authority: Some(&ctx.accounts.collection_authority.to_account_info()),
+                payer: &ctx.accounts.buyer.to_account_info(),
+                owner: Some(&ctx.accounts.buyer.to_account_info()),
+                update_authority: None,
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                log_wrapper: None,
+            },
+            CreateV2InstructionArgs {
+                data_state: DataState::AccountState,
+                name,
+                uri,
+                plugins: None,
+                external_plugin_adapters: None,
+            },
+        )
+        .invoke_signed(&[asset_seeds, coll_auth_seeds])?;
+
+        // 4) Update affiliate stats if provided
+        record_affiliate_mint(
+            &mut ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            1,
+            affiliate_cut,
+        )?;
+
+        // 5) Increment campaign minted count
+        campaign.minted = campaign.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
+
+use super::master_edition::MasterEditionMint;
+use super::payment::{buyer_rebate, pay_creator_and_affiliate, record_affiliate_mint, split_price};
+use crate::state::{AffiliateStats, AssetStandard, Campaign, SupplyShard};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(affiliate_maybe: Option<Pubkey>, name: String, symbol: String, uri: String)]
+pub struct ProcessMintSharded<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Read-only so parallel mints do not write-lock the campaign
+    #[account(has_one = collection_mint)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Counts the mint and holds the creator proceeds, so the creator wallet
+    /// is not written either
+    #[account(
+        mut,
+        seeds = [b"shard", campaign.key().as_ref(), &[supply_shard.index]],
+        bump = supply_shard.bump
+    )]
+    pub supply_shard: Account<'info, SupplyShard>,
+
+    /// CHECK: Affiliate receiver account - can be any account
+    #[account(mut)]
+    pub affiliate_receiver: UncheckedAccount<'info>,
+
+    /// Fresh mint keypair supplied (and signed) by the buyer
+    #[account(
+        init,
+        payer = buyer,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority
+    )]
+    pub nft_mint: Account<'info, Mint>,
+
+    /// Buyer's associated token account
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Mint authority PDA - verified by seeds constraint
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata account will be created by Metaplex CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition account will be created by Metaplex CPI
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Collection mint - verified via campaign constraint
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// Affiliate stats PDA, only passed when an affiliate is paid so mints
+    /// without one share no stats account
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + AffiliateStats::SIZE,
+        seeds = [b"affiliate", campaign.key().as_ref(), affiliate_receiver.key().as_ref()],
+        bump
+    )]
+    pub affiliate_stats: Option<Account<'info, AffiliateStats>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mints like `process_mint` for sharded campaigns. The mint is a buyer keypair
+/// rather than an index PDA, and the count and creator proceeds go to one
+/// supply shard, so buyers on different shards write no common account. Sized
+/// collection verification increments a counter on the collection metadata,
+/// so the item is left unverified here and verified later by
+/// `verify_sharded_mint`, off the buyer's path. Mints credited to the same
+/// affiliate still share that affiliate's stats account.
+pub fn process_mint_sharded_instruction(
+        ctx: Context<ProcessMintSharded>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        // 1) Supply check
+        require!(campaign.asset_standard == AssetStandard::TokenMetadata, ErrorCode::InvalidAssetStandard);
+        require!(campaign.supply_shards > 0, ErrorCode::ShardsNotConfigured);
+        require!(!campaign.has_process_mint_settings(), ErrorCode::ShardedUnsupported);
+        campaign.check_sale_window()?;
+        let shard = &ctx.accounts.supply_shard;
+        require!(shard.minted < shard.max_supply, ErrorCode::SoldOut);
+
+        // 2) Payment calculation & transfers; the creator cut is held by the shard
+        let affiliate_paid = affiliate_maybe.is_some() && ctx.accounts.affiliate_receiver.key() != Pubkey::default();
+        require!(
+            !affiliate_paid || ctx.accounts.affiliate_stats.is_some(),
+            ErrorCode::MissingAffiliateAccounts
+        );
+        let (creator_cut, affiliate_cut) = split_price(campaign.price, campaign.affiliate_fee_bps)?;
+        let rebate = ctx.accounts.affiliate_stats.as_ref().map_or(0, |stats| {
+            buyer_rebate(stats, affiliate_maybe, ctx.accounts.affiliate_receiver.key(), affiliate_cut)
+        });
+        let affiliate_cut = affiliate_cut - rebate;
+        pay_creator_and_affiliate(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.supply_shard.to_account_info(),
+            &ctx.accounts.affiliate_receiver.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            affiliate_maybe,
+            creator_cut,
+            affiliate_cut,
+        )?;
+
+        // 3) The buyer's mint keypair is initialized via the account constraints
+
+        // 4-6) Mint and create metadata/edition; collection verification is deferred
+        MasterEditionMint {
+            payer: ctx.accounts.buyer.to_account_info(),
+            nft_mint: ctx.accounts.nft_mint.to_account_info(),
+            token_account: ctx.accounts.buyer_ata.to_account_info(),
+            mint_authority: ctx.accounts.mint_authority.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            master_edition: ctx.accounts.master_edition.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        }
+        .mint(campaign, campaign.key(), name, symbol, uri)?;
+
+        // 8) Update affiliate stats if provided
+        if let Some(affiliate_stats) = ctx.accounts.affiliate_stats.as_mut() {
+            record_affiliate_mint(
+                affiliate_stats,
+                affiliate_maybe,
+                ctx.accounts.affiliate_receiver.key(),
+                1,
+                affiliate_cut,
+            )?;
+        }
+
+        // 9) Increment shard minted count
+        let shard = &mut ctx.accounts.supply_shard;
+        shard.minted = shard.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::spl_token_2022::{
+        extension::ExtensionType, instruction::AuthorityType, state::Mint as MintState,
+    },
+    token_interface::{
+        self, spl_token_metadata_interface::state::TokenMetadata, Mint, MintTo, SetAuthority,
+        Token2022, TokenAccount, TokenMemberInitialize, TokenMetadataInitialize,
+    },
+};
+
+use super::payment::{buyer_rebate, pay_creator_and_affiliate, record_affiliate_mint, split_price, transfer_lamports};
+use crate::state::{AffiliateStats, AssetStandard, Campaign};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(affiliate_maybe: Option<Pubkey>, name: String, symbol: String, uri: String)]
+pub struct ProcessMintToken2022<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut, has_one = collection_mint)]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// CHECK: Creator account verified via campaign.creator constraint
+    #[account(mut, address = campaign.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: Affiliate receiver account - can be any account
+    #[account(mut)]
+    pub affiliate_receiver: UncheckedAccount<'info>,
+
+    /// Token-2022 NFT mint PDA with metadata and group member pointers to itself
+    #[account(
+        init,
+        payer = buyer,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = mint_authority,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+        extensions::group_member_pointer::authority = mint_authority,
+        extensions::group_member_pointer::member_address = nft_mint,
+        seeds = [b"nft_mint", campaign.key().as_ref(), &campaign.minted.to_le_bytes()],
+        bump
+    )]
+    pub nft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Buyer's associated token account
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program
+    )]
+    pub buyer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Mint authority PDA - mint, metadata and group authority
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Collection group mint - verified via campaign constraint
+    #[account(mut)]
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// Affiliate stats PDA
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + AffiliateStats::SIZE,
+        seeds = [b"affiliate", campaign.key().as_ref(), affiliate_receiver.key().as_ref()],
+        bump
+    )]
+    pub affiliate_stats: Box<Account<'info, AffiliateStats>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buyer mints a Token-2022 NFT carrying its own metadata, as a member of the
+/// campaign's collection group. No Metaplex program is involved.
+pub fn process_mint_token_2022_instruction(
+        ctx: Context<ProcessMintToken2022>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        // 1) Supply check
+        require!(campaign.asset_standard == AssetStandard::Token2022, ErrorCode::InvalidAssetStandard);
+        campaign.check_mintable(1)?;
+
+        // 2) Payment calculation & transfers
+        let (creator_cut, affiliate_cut) = split_price(campaign.price, campaign.affiliate_fee_bps)?;
+        // The affiliate's buyer rebate comes out of the affiliate cut only
+        let rebate = buyer_rebate(
+            &ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            affiliate_cut,
+        );
+        let affiliate_cut = affiliate_cut - rebate;
+        pay_creator_and_affiliate(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.affiliate_receiver.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            affiliate_maybe,
+            creator_cut,
+            affiliate_cut,
+        )?;
+
+        // 3) Fund the mint for the metadata and group member extensions
+        let metadata = TokenMetadata { name, symbol, uri, ..Default::default() };
+        let base_len = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::MetadataPointer,
+            ExtensionType::GroupMemberPointer,
+            ExtensionType::TokenGroupMember,
+        ])?;
+        let total_len = base_len.checked_add(metadata.tlv_size_of()?).ok_or(ErrorCode::MathOverflow)?;
+        let nft_mint_info = ctx.accounts.nft_mint.to_account_info();
+        let top_up = Rent::get()?.minimum_balance(total_len).saturating_sub(nft_mint_info.lamports());
+        if top_up > 0 {
+            transfer_lamports(
+                &ctx.accounts.buyer.to_account_info(),
+                &nft_mint_info,
+                &ctx.accounts.system_program.to_account_info(),
+                top_up,
+            )?;
+        }
+
+        let binding = campaign.key();
+        let seeds_for_mint_auth: &[&[u8]] = &[b"mint_auth", binding.as_ref(), &[campaign.mint_authority_bump]];
+        let signer_seeds_mint_auth = &[seeds_for_mint_auth];
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let mint_authority = ctx.accounts.mint_authority.to_account_info();
+
+        // 4) Initialize metadata stored on the mint itself
+        token_interface::token_metadata_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataInitialize {
+                    program_id: token_program.clone(),
+                    metadata: nft_mint_info.clone(),
+                    update_authority: mint_authority.clone(),
+                    mint_authority: mint_authority.clone(),
+                    mint: nft_mint_info.clone(),
+                },
+                signer_seeds_mint_auth,
+            ),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        )?;
+
+        // 5) Join the collection group
+        token_interface::token_member_initialize(CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMemberInitialize {
+                program_id: token_program.clone(),
+                member: nft_mint_info.clone(),
+                member_mint: nft_mint_info.clone(),
+                member_mint_authority: mint_authority.clone(),
+                group: ctx.accounts.collection_mint.to_account_info(),
+                group_update_authority: mint_authority.clone(),
+            },
+            signer_seeds_mint_auth,
+        ))?;
+
+        // 6) Mint token to buyer
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                MintTo {
+                    mint: nft_mint_info.clone(),
+                    to: ctx.accounts.buyer_ata.to_account_info(),
+                    authority: mint_authority.clone(),
+                },
+                signer_seeds_mint_auth,
+            ),
+            1,
+        )?;
+
+        // 7) Drop the mint authority so supply stays at one
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                token_program,
+                SetAuthority {
+                    current_authority: mint_authority,
+                    account_or_mint: nft_mint_info,
+                },
+                signer_seeds_mint_auth,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        // 8) Update affiliate stats if provided
+        record_affiliate_mint(
+            &mut ctx.accounts.affiliate_stats,
+            affiliate_maybe,
+            ctx.accounts.affiliate_receiver.key(),
+            1,
+            affiliate_cut,
+        )?;
+
+        // 9) Increment campaign minted count
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.minted = campaign.minted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+/// Most prices returned by one quote, keeping the return data under its limit.
+pub const MAX_QUOTE: u8 = 100;
+
+#[derive(Accounts)]
+pub struct QuotePrices<'info> {
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Read-only: returns the list prices of the next `count` mints, before
+/// phases, allowlists, vouchers and discounts. Meant to be simulated.
+pub fn quote_prices_instruction(ctx: Context<QuotePrices>, count: u8) -> Result<Vec<u64>> {
+        require!(count <= MAX_QUOTE, ErrorCode::InvalidQuantity);
+
+        let campaign = &ctx.accounts.campaign;
+        let now = Clock::get()?.unix_timestamp;
+        (0..count as u64)
+            .map(|i| {
+                let minted = campaign.minted.checked_add(i).ok_or(ErrorCode::MathOverflow)?;
+                campaign.price_for(minted, now)
+            })
+            .collect()
+    }
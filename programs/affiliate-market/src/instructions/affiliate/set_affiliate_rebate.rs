@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AffiliateStats, Campaign};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetAffiliateRebate<'info> {
+    #[account(mut)]
+    pub affiliate: Signer<'info>,
+
+    pub campaign: Account<'info, Campaign>,
+
+    /// Affiliate registration for this campaign
+    #[account(
+        init_if_needed,
+        payer = affiliate,
+        space = 8 + AffiliateStats::SIZE,
+        seeds = [b"affiliate", campaign.key().as_ref(), affiliate.key().as_ref()],
+        bump
+    )]
+    pub affiliate_stats: Account<'info, AffiliateStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets an affiliate share its commission: every mint path lowers the price by
+/// `buyer_rebate_bps` of the affiliate cut, leaving the creator cut untouched.
+pub fn set_affiliate_rebate_instruction(ctx: Context<SetAffiliateRebate>, buyer_rebate_bps: u16) -> Result<()> {
+        require!(buyer_rebate_bps <= 10000, ErrorCode::InvalidRebate);
+        ctx.accounts.affiliate_stats.buyer_rebate_bps = buyer_rebate_bps;
+
+        Ok(())
+    }
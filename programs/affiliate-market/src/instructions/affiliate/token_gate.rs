@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
+
+use crate::error::ErrorCode;
+
+/// Checks that `holder` owns at least one token of a mint whose metadata is a
+/// verified member of `collection`.
+pub fn verify_gate_holding(
+    holder: &Pubkey,
+    token_account: &TokenAccount,
+    metadata_info: &AccountInfo,
+    collection: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(token_account.owner, *holder, ErrorCode::InvalidGateToken);
+    require!(token_account.amount >= 1, ErrorCode::InvalidGateToken);
+
+    let (expected_metadata, _) = Metadata::find_pda(&token_account.mint);
+    require_keys_eq!(metadata_info.key(), expected_metadata, ErrorCode::InvalidGateToken);
+    require_keys_eq!(*metadata_info.owner, MPL_TOKEN_METADATA_ID, ErrorCode::InvalidGateToken);
+
+    let metadata = Metadata::safe_deserialize(&metadata_info.try_borrow_data()?)
+        .map_err(|_| ErrorCode::InvalidGateToken)?;
+    let verified = metadata
+        .collection
+        .is_some_and(|c| c.verified && c.key == *collection);
+    require!(verified, ErrorCode::InvalidGateToken);
+
+    Ok(())
+}
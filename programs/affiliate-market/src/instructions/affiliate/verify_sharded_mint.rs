@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
+
+use super::master_edition::CollectionVerification;
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct VerifyShardedMint<'info> {
+    /// Any caller - signs as Token Metadata's verification payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(has_one = collection_mint)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Metadata of a sharded mint - verified in the handler
+    #[account(mut, owner = MPL_TOKEN_METADATA_ID)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Mint authority PDA - verified by seeds constraint
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Collection mint - verified via campaign constraint
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Collection metadata account
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition account
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Collection authority PDA - verified by seeds constraint
+    #[account(
+        seeds = [b"collection_auth", campaign.key().as_ref()],
+        bump = campaign.collection_auth_bump
+    )]
+    pub collection_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex token metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+/// Verifies an item minted by `process_mint_sharded` into the campaign's
+/// sized collection. Anyone can call it; only unverified items whose update
+/// authority is the campaign mint authority qualify, so NFTs made elsewhere
+/// that merely name the collection are rejected.
+pub fn verify_sharded_mint_instruction(ctx: Context<VerifyShardedMint>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        require!(campaign.supply_shards > 0, ErrorCode::ShardsNotConfigured);
+
+        let metadata = Metadata::safe_deserialize(&ctx.accounts.metadata.try_borrow_data()?)
+            .map_err(|_| ErrorCode::ItemNotPending)?;
+        require_keys_eq!(metadata.update_authority, ctx.accounts.mint_authority.key(), ErrorCode::ItemNotPending);
+        let pending = metadata
+            .collection
+            .is_some_and(|c| !c.verified && c.key == campaign.collection_mint);
+        require!(pending, ErrorCode::ItemNotPending);
+
+        CollectionVerification {
+            payer: ctx.accounts.payer.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            collection_authority: ctx.accounts.collection_authority.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        }
+        .verify(campaign, campaign.key())
+    }
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+
+use crate::error::ErrorCode;
+
+/// Mint authorization signed off-chain by the campaign voucher signer. The
+/// signed message is the campaign key followed by this struct, Borsh encoded.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintVoucher {
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub affiliate: Option<Pubkey>,
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
+impl MintVoucher {
+    pub fn message(&self, campaign: &Pubkey) -> Result<Vec<u8>> {
+        let mut message = campaign.to_bytes().to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+// Layout of one Ed25519SignatureOffsets entry in the precompile data
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// Checks that the instruction right before this one is an ed25519 precompile
+/// verifying `message` signed by `signer`, with all data inline.
+pub fn verify_ed25519_instruction(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ErrorCode::InvalidVoucherSignature);
+    let ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, ErrorCode::InvalidVoucherSignature);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE && data[0] == 1,
+        ErrorCode::InvalidVoucherSignature
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = SIGNATURE_OFFSETS_START;
+    let signature_ix_index = read_u16(offsets + 2);
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let public_key_ix_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_ix_index = read_u16(offsets + 12);
+
+    // Data must live in the precompile instruction itself
+    require!(
+        signature_ix_index == u16::MAX && public_key_ix_index == u16::MAX && message_ix_index == u16::MAX,
+        ErrorCode::InvalidVoucherSignature
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(ErrorCode::InvalidVoucherSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidVoucherSignature)?;
+    require!(public_key == signer.as_ref(), ErrorCode::InvalidVoucherSignature);
+    require!(signed_message == message, ErrorCode::InvalidVoucherSignature);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::sysvar::instructions::{
+        construct_instructions_data, BorrowedInstruction, ID as INSTRUCTIONS_SYSVAR_ID,
+    };
+
+    const DATA_START: usize = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE;
+
+    /// Precompile data for one inline signature: offsets, public key, a dummy
+    /// signature (the runtime checks it, not this program), then the message.
+    fn ed25519_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let public_key_offset = DATA_START as u16;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + 64;
+        let mut data = vec![1, 0];
+        for field in [
+            signature_offset,
+            u16::MAX,
+            public_key_offset,
+            u16::MAX,
+            message_offset,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    /// Runs the check as instruction `current` of a transaction made of
+    /// `(program_id, data)` instructions.
+    fn verify(instructions: &[(Pubkey, Vec<u8>)], current: u16, signer: &Pubkey, message: &[u8]) -> Result<()> {
+        let borrowed: Vec<BorrowedInstruction> = instructions
+            .iter()
+            .map(|(program_id, data)| BorrowedInstruction { program_id, accounts: vec![], data })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed);
+        // the sysvar ends with the index of the executing instruction
+        let len = data.len();
+        data[len - 2..].copy_from_slice(&current.to_le_bytes());
+
+        let mut lamports = 0;
+        let owner = Pubkey::default();
+        let sysvar = AccountInfo::new(&INSTRUCTIONS_SYSVAR_ID, false, false, &mut lamports, &mut data, &owner, false, 0);
+        verify_ed25519_instruction(&sysvar, signer, message)
+    }
+
+    fn invalid() -> Error {
+        error!(ErrorCode::InvalidVoucherSignature)
+    }
+
+    #[test]
+    fn accepts_matching_precompile_right_before() {
+        let signer = Pubkey::new_unique();
+        let ixs = [(ed25519_program::ID, ed25519_data(&signer, b"voucher")), (crate::ID, vec![])];
+        assert!(verify(&ixs, 1, &signer, b"voucher").is_ok());
+    }
+
+    #[test]
+    fn rejects_wrong_signature_count() {
+        let signer = Pubkey::new_unique();
+        for count in [0, 2] {
+            let mut data = ed25519_data(&signer, b"voucher");
+            data[0] = count;
+            let ixs = [(ed25519_program::ID, data), (crate::ID, vec![])];
+            assert_eq!(verify(&ixs, 1, &signer, b"voucher").unwrap_err(), invalid());
+        }
+    }
+
+    #[test]
+    fn rejects_data_from_other_instructions() {
+        let signer = Pubkey::new_unique();
+        // signature, public key and message instruction indices
+        for field in [4, 8, 14] {
+            let mut data = ed25519_data(&signer, b"voucher");
+            data[field..field + 2].copy_from_slice(&0u16.to_le_bytes());
+            let ixs = [(ed25519_program::ID, data), (crate::ID, vec![])];
+            assert_eq!(verify(&ixs, 1, &signer, b"voucher").unwrap_err(), invalid());
+        }
+    }
+
+    #[test]
+    fn rejects_precompile_not_right_before() {
+        let signer = Pubkey::new_unique();
+        let ixs = [
+            (ed25519_program::ID, ed25519_data(&signer, b"voucher")),
+            (Pubkey::new_unique(), vec![]),
+            (crate::ID, vec![]),
+        ];
+        assert_eq!(verify(&ixs, 2, &signer, b"voucher").unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_mismatched_pubkey_or_message() {
+        let signer = Pubkey::new_unique();
+        let ixs = [(ed25519_program::ID, ed25519_data(&signer, b"voucher")), (crate::ID, vec![])];
+        assert_eq!(verify(&ixs, 1, &Pubkey::new_unique(), b"voucher").unwrap_err(), invalid());
+        assert_eq!(verify(&ixs, 1, &signer, b"other").unwrap_err(), invalid());
+        assert_eq!(verify(&ixs, 1, &signer, b"voucher!").unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_missing_precompile() {
+        let signer = Pubkey::new_unique();
+        assert_eq!(verify(&[(crate::ID, vec![])], 0, &signer, b"voucher").unwrap_err(), invalid());
+        let ixs = [(Pubkey::new_unique(), ed25519_data(&signer, b"voucher")), (crate::ID, vec![])];
+        assert_eq!(verify(&ixs, 1, &signer, b"voucher").unwrap_err(), invalid());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let signer = Pubkey::new_unique();
+        let mut data = ed25519_data(&signer, b"voucher");
+        data.truncate(DATA_START + 16);
+        let ixs = [(ed25519_program::ID, data), (crate::ID, vec![])];
+        assert_eq!(verify(&ixs, 1, &signer, b"voucher").unwrap_err(), invalid());
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AssetStandard, Campaign};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ConfigureSupplyShards<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(mut, has_one = creator)]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Splits the campaign supply counter across `shard_count` shard accounts, each
+/// created with `create_supply_shard`. Only before the first mint, and only
+/// for campaigns without settings that `process_mint_sharded` cannot apply.
+pub fn configure_supply_shards_instruction(ctx: Context<ConfigureSupplyShards>, shard_count: u8) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        require!(campaign.asset_standard == AssetStandard::TokenMetadata, ErrorCode::InvalidAssetStandard);
+        require!(campaign.supply_shards == 0, ErrorCode::ShardsAlreadyConfigured);
+        require!(campaign.minted == 0, ErrorCode::ShardsAlreadyConfigured);
+        require!(shard_count > 0, ErrorCode::InvalidShard);
+        require!(!campaign.has_process_mint_settings(), ErrorCode::ShardedUnsupported);
+
+        campaign.supply_shards = shard_count;
+
+        Ok(())
+    }
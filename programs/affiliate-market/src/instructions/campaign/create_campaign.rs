@@ -1,10 +1,44 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token::{self, InitializeMint2, Mint, MintTo, Token},
+    token_2022::spl_token_2022::{extension::ExtensionType, state::Mint as MintState},
+    token_interface::{
+        self, spl_token_metadata_interface::state::TokenMetadata, GroupPointerInitialize,
+        MetadataPointerInitialize, Token2022, TokenGroupInitialize, TokenMetadataInitialize,
+    },
+};
+use mpl_token_metadata::instructions::{
+    CreateMetadataAccountV3Cpi, CreateMetadataAccountV3CpiAccounts, CreateMetadataAccountV3InstructionArgs,
+    CreateMasterEditionV3Cpi, CreateMasterEditionV3CpiAccounts, CreateMasterEditionV3InstructionArgs,
+};
+use mpl_token_metadata::types::{CollectionDetails, DataV2};
+use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
+use mpl_core::instructions::{CreateCollectionV2Cpi, CreateCollectionV2CpiAccounts, CreateCollectionV2InstructionArgs};
+use mpl_core::ID as MPL_CORE_ID;
 
-use crate::state::Campaign;
+use crate::state::{AssetStandard, Campaign, PricingMode};
 use crate::error::ErrorCode;
 
+/// Collection NFT data used when the program creates the collection itself.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollectionArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
 #[derive(Accounts)]
-#[instruction(price: u64, affiliate_fee_bps: u16, max_supply: u64)]
+#[instruction(
+    price: u64,
+    affiliate_fee_bps: u16,
+    max_supply: u64,
+    asset_standard: AssetStandard,
+    rule_set: Option<Pubkey>,
+    open_edition_end_time: Option<i64>,
+    collection: Option<CollectionArgs>
+)]
 pub struct CreateCampaign<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -18,7 +52,8 @@ pub struct CreateCampaign<'info> {
     )]
     pub campaign: Box<Account<'info, Campaign>>,
 
-    /// CHECK: Collection mint created externally by creator
+    /// CHECK: Collection mint created externally by creator, or a fresh signer
+    /// keypair when the program creates the collection
     pub collection_mint: UncheckedAccount<'info>,
 
     /// CHECK: Collection authority PDA - program-controlled authority
@@ -37,17 +72,50 @@ pub struct CreateCampaign<'info> {
     )]
     pub mint_authority: UncheckedAccount<'info>,
 
+    /// CHECK: Creator's collection token account - created by the ATA program CPI
+    #[account(mut)]
+    pub collection_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Collection metadata account will be created by Metaplex CPI
+    #[account(mut)]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Collection master edition account will be created by Metaplex CPI
+    #[account(mut)]
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Metaplex Core program
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
  /// Creator creates campaign, storing collection_mint and bump info.
+ /// When `collection` is provided the collection is created here with the
+ /// collection_auth PDA as update authority, matching `asset_standard`.
+ /// `open_edition_end_time` makes it an uncapped open edition selling until then.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_campaign_instruction(
         ctx: Context<CreateCampaign>,
         price: u64,
         affiliate_fee_bps: u16,
         max_supply: u64,
+        asset_standard: AssetStandard,
+        rule_set: Option<Pubkey>,
+        open_edition_end_time: Option<i64>,
+        collection: Option<CollectionArgs>,
     ) -> Result<()> {
+        require!(
+            rule_set.is_none() || asset_standard == AssetStandard::Programmable,
+            ErrorCode::InvalidAssetStandard
+        );
         require!(affiliate_fee_bps <= 10000, ErrorCode::InvalidFee);
 
         let campaign = &mut ctx.accounts.campaign;
@@ -57,10 +125,322 @@ pub struct CreateCampaign<'info> {
         campaign.affiliate_fee_bps = affiliate_fee_bps;
         campaign.minted = 0;
         campaign.max_supply = max_supply;
+        campaign.asset_standard = asset_standard;
+        campaign.merkle_tree = Pubkey::default();
+        campaign.rule_set = rule_set;
+
+        // Open editions have no cap until finalized, only a mandatory end time
+        if let Some(end_time) = open_edition_end_time {
+            require!(end_time > Clock::get()?.unix_timestamp, ErrorCode::InvalidEndTime);
+            campaign.open_edition = true;
+            campaign.end_time = end_time;
+            campaign.max_supply = 0;
+        } else {
+            campaign.open_edition = false;
+            campaign.end_time = 0;
+        }
+        campaign.finalized = false;
+        campaign.supply_shards = 0;
+        campaign.rent_sponsored = false;
+        campaign.rent_sponsor_bump = 0;
+        campaign.max_per_wallet = 0;
+        campaign.allowlist_root = None;
+        campaign.phased = false;
+        campaign.gate_collection = None;
+        campaign.gate_single_use = false;
+        campaign.voucher_signer = None;
+        campaign.required_cosigner = None;
+        campaign.bot_tax = 0;
+        campaign.guarded = false;
+        campaign.guard_program = None;
+        campaign.post_mint_hook = None;
+        campaign.post_mint_hook_fatal = false;
+        campaign.pricing = PricingMode::Fixed;
+        campaign.clearing_price = 0;
 
         // store bumps from ctx.bumps (dot access)
         campaign.mint_authority_bump = ctx.bumps.mint_authority;
         campaign.collection_auth_bump = ctx.bumps.collection_authority;
 
+        if let Some(args) = collection {
+            match asset_standard {
+                AssetStandard::TokenMetadata
+                | AssetStandard::Compressed
+                | AssetStandard::Programmable
+                | AssetStandard::PrintEdition => {
+                    create_collection_nft(&ctx, args)?
+                }
+                AssetStandard::Core => create_core_collection(&ctx, args)?,
+                AssetStandard::Token2022 => create_token_2022_group(&ctx, args)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates the collection mint, mints the single collection token to the creator
+    /// and creates its metadata and master edition, all under the collection_auth PDA.
+    fn create_collection_nft(ctx: &Context<CreateCampaign>, args: CollectionArgs) -> Result<()> {
+        let collection_mint = &ctx.accounts.collection_mint;
+        require!(collection_mint.is_signer, ErrorCode::CollectionMintNotSigner);
+
+        let (
+            Some(collection_token_account),
+            Some(collection_metadata),
+            Some(collection_master_edition),
+            Some(token_program),
+            Some(associated_token_program),
+            Some(token_metadata_program),
+        ) = (
+            ctx.accounts.collection_token_account.as_ref(),
+            ctx.accounts.collection_metadata.as_ref(),
+            ctx.accounts.collection_master_edition.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.associated_token_program.as_ref(),
+            ctx.accounts.token_metadata_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingCollectionAccounts);
+        };
+
+        let campaign_key = ctx.accounts.campaign.key();
+        let coll_auth_seeds: &[&[u8]] = &[b"collection_auth", campaign_key.as_ref(), &[ctx.bumps.collection_authority]];
+        let signer_seeds_collection_auth = &[coll_auth_seeds];
+
+        // 1) Create and initialize the collection mint
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: collection_mint.to_account_info(),
+                },
+            ),
+            ctx.accounts.rent.minimum_balance(Mint::LEN),
+            Mint::LEN as u64,
+            &token_program.key(),
+        )?;
+
+        token::initialize_mint2(
+            CpiContext::new(
+                token_program.to_account_info(),
+                InitializeMint2 { mint: collection_mint.to_account_info() },
+            ),
+            0,
+            &ctx.accounts.collection_authority.key(),
+            Some(&ctx.accounts.collection_authority.key()),
+        )?;
+
+        // 2) Mint the collection token to the creator
+        associated_token::create(CpiContext::new(
+            associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.creator.to_account_info(),
+                associated_token: collection_token_account.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+                mint: collection_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+            },
+        ))?;
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: collection_mint.to_account_info(),
+                    to: collection_token_account.to_account_info(),
+                    authority: ctx.accounts.collection_authority.to_account_info(),
+                },
+                signer_seeds_collection_auth,
+            ),
+            1,
+        )?;
+
+        // 3) Create sized collection metadata
+        let data_v2 = DataV2 {
+            name: args.name,
+            symbol: args.symbol,
+            uri: args.uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        };
+
+        CreateMetadataAccountV3Cpi::new(
+            &token_metadata_program.to_account_info(),
+            CreateMetadataAccountV3CpiAccounts {
+                metadata: &collection_metadata.to_account_info(),
+                mint: &collection_mint.to_account_info(),
+                mint_authority: &ctx.accounts.collection_authority.to_account_info(),
+                payer: &ctx.accounts.creator.to_account_info(),
+                update_authority: (&ctx.accounts.collection_authority.to_account_info(), true),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                rent: Some(&ctx.accounts.rent.to_account_info()),
+            },
+            CreateMetadataAccountV3InstructionArgs {
+                data: data_v2,
+                is_mutable: true,
+                collection_details: Some(CollectionDetails::V1 { size: 0 }),
+            },
+        )
+        .invoke_signed(signer_seeds_collection_auth)?;
+
+        // 4) Create collection master edition
+        CreateMasterEditionV3Cpi::new(
+            &token_metadata_program.to_account_info(),
+            CreateMasterEditionV3CpiAccounts {
+                edition: &collection_master_edition.to_account_info(),
+                mint: &collection_mint.to_account_info(),
+                update_authority: &ctx.accounts.collection_authority.to_account_info(),
+                mint_authority: &ctx.accounts.collection_authority.to_account_info(),
+                payer: &ctx.accounts.creator.to_account_info(),
+                metadata: &collection_metadata.to_account_info(),
+                token_program: &token_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                rent: Some(&ctx.accounts.rent.to_account_info()),
+            },
+            CreateMasterEditionV3InstructionArgs { max_supply: Some(0) },
+        )
+        .invoke_signed(signer_seeds_collection_auth)?;
+
+        Ok(())
+    }
+
+    /// Creates a Core collection whose update authority is the collection_auth PDA.
+    fn create_core_collection(ctx: &Context<CreateCampaign>, args: CollectionArgs) -> Result<()> {
+        let collection = &ctx.accounts.collection_mint;
+        require!(collection.is_signer, ErrorCode::CollectionMintNotSigner);
+
+        let Some(mpl_core_program) = ctx.accounts.mpl_core_program.as_ref() else {
+            return err!(ErrorCode::MissingCollectionAccounts);
+        };
+
+        CreateCollectionV2Cpi::new(
+            &mpl_core_program.to_account_info(),
+            CreateCollectionV2CpiAccounts {
+                collection: &collection.to_account_info(),
+                update_authority: Some(&ctx.accounts.collection_authority.to_account_info()),
+                payer: &ctx.accounts.creator.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+            },
+            CreateCollectionV2InstructionArgs {
+                name: args.name,
+                uri: args.uri,
+                plugins: None,
+                external_plugin_adapters: None,
+            },
+        )
+        .invoke()?;
+
+        Ok(())
+    }
+
+    /// Creates a Token-2022 collection mint carrying its own metadata and a token
+    /// group sized to the campaign max supply (unbounded for open editions), all
+    /// under the mint_auth PDA.
+    fn create_token_2022_group(ctx: &Context<CreateCampaign>, args: CollectionArgs) -> Result<()> {
+        let collection_mint = &ctx.accounts.collection_mint;
+        require!(collection_mint.is_signer, ErrorCode::CollectionMintNotSigner);
+
+        let Some(token_program) = ctx.accounts.token_2022_program.as_ref() else {
+            return err!(ErrorCode::MissingCollectionAccounts);
+        };
+
+        let campaign_key = ctx.accounts.campaign.key();
+        let mint_auth_seeds: &[&[u8]] = &[b"mint_auth", campaign_key.as_ref(), &[ctx.bumps.mint_authority]];
+        let signer_seeds_mint_auth = &[mint_auth_seeds];
+        let mint_authority = ctx.accounts.mint_authority.to_account_info();
+        let mint_info = collection_mint.to_account_info();
+        let token_program = token_program.to_account_info();
+
+        // 1) Create the mint with pointer extensions, funded for group and metadata
+        let metadata = TokenMetadata { name: args.name, symbol: args.symbol, uri: args.uri, ..Default::default() };
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::GroupPointer,
+            ExtensionType::MetadataPointer,
+        ])?;
+        let funded_len = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::GroupPointer,
+            ExtensionType::MetadataPointer,
+            ExtensionType::TokenGroup,
+        ])?
+        .checked_add(metadata.tlv_size_of()?)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            ctx.accounts.rent.minimum_balance(funded_len),
+            space as u64,
+            &token_program.key(),
+        )?;
+
+        token_interface::group_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                GroupPointerInitialize { token_program_id: token_program.clone(), mint: mint_info.clone() },
+            ),
+            Some(mint_authority.key()),
+            Some(mint_info.key()),
+        )?;
+
+        token_interface::metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MetadataPointerInitialize { token_program_id: token_program.clone(), mint: mint_info.clone() },
+            ),
+            Some(mint_authority.key()),
+            Some(mint_info.key()),
+        )?;
+
+        token_interface::initialize_mint2(
+            CpiContext::new(
+                token_program.clone(),
+                token_interface::InitializeMint2 { mint: mint_info.clone() },
+            ),
+            0,
+            &mint_authority.key(),
+            None,
+        )?;
+
+        // 2) Initialize the collection group and its metadata
+        token_interface::token_group_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenGroupInitialize {
+                    program_id: token_program.clone(),
+                    group: mint_info.clone(),
+                    mint: mint_info.clone(),
+                    mint_authority: mint_authority.clone(),
+                },
+                signer_seeds_mint_auth,
+            ),
+            Some(mint_authority.key()),
+            if ctx.accounts.campaign.open_edition { u64::MAX } else { ctx.accounts.campaign.max_supply },
+        )?;
+
+        token_interface::token_metadata_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataInitialize {
+                    program_id: token_program,
+                    metadata: mint_info.clone(),
+                    update_authority: mint_authority.clone(),
+                    mint_authority,
+                    mint: mint_info,
+                },
+                signer_seeds_mint_auth,
+            ),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        )?;
+
         Ok(())
     }
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::instructions::{CreateTreeConfigCpi, CreateTreeConfigCpiAccounts, CreateTreeConfigInstructionArgs};
+use mpl_bubblegum::ID as MPL_BUBBLEGUM_ID;
+
+use crate::constants::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::state::{AssetStandard, Campaign};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateCampaignTree<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut, has_one = creator)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Merkle tree account allocated by the creator, initialized by Bubblegum
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum tree config PDA - verified by Bubblegum
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Mint authority PDA - becomes the tree creator
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum program
+    #[account(address = MPL_BUBBLEGUM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creator registers the merkle tree compressed NFTs are minted into. The tree
+/// is created under the campaign mint_auth PDA so only this program can mint.
+pub fn create_campaign_tree_instruction(
+        ctx: Context<CreateCampaignTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        require!(campaign.asset_standard == AssetStandard::Compressed, ErrorCode::InvalidAssetStandard);
+        require!(campaign.merkle_tree == Pubkey::default(), ErrorCode::TreeAlreadySet);
+
+        let capacity = 1u64.checked_shl(max_depth).ok_or(ErrorCode::MathOverflow)?;
+        require!(capacity >= campaign.max_supply, ErrorCode::TreeTooSmall);
+
+        let campaign_key = campaign.key();
+        let mint_auth_seeds: &[&[u8]] = &[b"mint_auth", campaign_key.as_ref(), &[campaign.mint_authority_bump]];
+
+        CreateTreeConfigCpi::new(
+            &ctx.accounts.bubblegum_program.to_account_info(),
+            CreateTreeConfigCpiAccounts {
+                tree_config: &ctx.accounts.tree_config.to_account_info(),
+                merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+                payer: &ctx.accounts.creator.to_account_info(),
+                tree_creator: &ctx.accounts.mint_authority.to_account_info(),
+                log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+            },
+            CreateTreeConfigInstructionArgs {
+                max_depth,
+                max_buffer_size,
+                public: Some(false),
+            },
+        )
+        .invoke_signed(&[mint_auth_seeds])?;
+
+        campaign.merkle_tree = ctx.accounts.merkle_tree.key();
+
+        Ok(())
+    }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Campaign, Coupon, Discount};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(code_hash: [u8; 32])]
+pub struct CreateCoupon<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Coupon::SIZE,
+        seeds = [b"coupon", campaign.key().as_ref(), code_hash.as_ref()],
+        bump
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a discount code by its keccak hash, so the code itself stays off
+/// chain until a buyer redeems it in `process_mint`.
+pub fn create_coupon_instruction(
+        ctx: Context<CreateCoupon>,
+        _code_hash: [u8; 32],
+        discount: Discount,
+        max_uses: u64,
+        expiry: i64,
+    ) -> Result<()> {
+        if let Discount::Percent { bps } = discount {
+            require!(bps <= 10000, ErrorCode::InvalidDiscount);
+        }
+
+        let coupon = &mut ctx.accounts.coupon;
+        coupon.discount = discount;
+        coupon.max_uses = max_uses;
+        coupon.uses = 0;
+        coupon.expiry = expiry;
+
+        Ok(())
+    }
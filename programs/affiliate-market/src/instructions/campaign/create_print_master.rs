@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, MintTo, Token, TokenAccount},
+};
+use mpl_token_metadata::instructions::{
+    CreateMetadataAccountV3Cpi, CreateMetadataAccountV3CpiAccounts, CreateMetadataAccountV3InstructionArgs,
+    CreateMasterEditionV3Cpi, CreateMasterEditionV3CpiAccounts, CreateMasterEditionV3InstructionArgs,
+};
+use mpl_token_metadata::types::{Collection, DataV2};
+use mpl_token_metadata::ID as MPL_TOKEN_METADATA_ID;
+
+use crate::state::{AssetStandard, Campaign};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreatePrintMaster<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(has_one = creator, has_one = collection_mint)]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Master mint PDA - one per campaign
+    #[account(
+        init,
+        payer = creator,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        seeds = [b"master_mint", campaign.key().as_ref()],
+        bump
+    )]
+    pub master_mint: Box<Account<'info, Mint>>,
+
+    /// Program-held token account for the master token
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = master_mint,
+        associated_token::authority = mint_authority
+    )]
+    pub master_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Mint authority PDA - owns the master token and signs prints
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Master metadata account will be created by Metaplex CPI
+    #[account(mut)]
+    pub master_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition account will be created by Metaplex CPI
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Collection mint - verified via campaign constraint
+    pub collection_mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Creator sets up the single master edition that `process_mint` prints
+/// numbered editions from. Its max supply is the campaign max supply, or
+/// unlimited for open editions.
+pub fn create_print_master_instruction(
+        ctx: Context<CreatePrintMaster>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        require!(campaign.asset_standard == AssetStandard::PrintEdition, ErrorCode::InvalidAssetStandard);
+
+        let binding = campaign.key();
+        let seeds_for_mint_auth: &[&[u8]] = &[b"mint_auth", binding.as_ref(), &[campaign.mint_authority_bump]];
+        let signer_seeds_mint_auth = &[seeds_for_mint_auth];
+
+        // 1) Mint the master token into the program-held account
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.master_mint.to_account_info(),
+                    to: ctx.accounts.master_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds_mint_auth,
+            ),
+            1,
+        )?;
+
+        // 2) Create master metadata; prints inherit its collection
+        let data_v2 = DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: Some(Collection { verified: false, key: campaign.collection_mint }),
+            uses: None,
+        };
+
+        CreateMetadataAccountV3Cpi::new(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountV3CpiAccounts {
+                metadata: &ctx.accounts.master_metadata.to_account_info(),
+                mint: &ctx.accounts.master_mint.to_account_info(),
+                mint_authority: &ctx.accounts.mint_authority.to_account_info(),
+                payer: &ctx.accounts.creator.to_account_info(),
+                update_authority: (&ctx.accounts.mint_authority.to_account_info(), true),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                rent: Some(&ctx.accounts.rent.to_account_info()),
+            },
+            CreateMetadataAccountV3InstructionArgs {
+                data: data_v2,
+                is_mutable: true,
+                collection_details: None,
+            },
+        )
+        .invoke_signed(signer_seeds_mint_auth)?;
+
+        // 3) Create master edition limited to the campaign supply
+        let max_supply = if campaign.open_edition { None } else { Some(campaign.max_supply) };
+        CreateMasterEditionV3Cpi::new(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3CpiAccounts {
+                edition: &ctx.accounts.master_edition.to_account_info(),
+                mint: &ctx.accounts.master_mint.to_account_info(),
+                update_authority: &ctx.accounts.mint_authority.to_account_info(),
+                mint_authority: &ctx.accounts.mint_authority.to_account_info(),
+                payer: &ctx.accounts.creator.to_account_info(),
+                metadata: &ctx.accounts.master_metadata.to_account_info(),
+                token_program: &ctx.accounts.token_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                rent: Some(&ctx.accounts.rent.to_account_info()),
+            },
+            CreateMasterEditionV3InstructionArgs { max_supply },
+        )
+        .invoke_signed(signer_seeds_mint_auth)?;
+
+        Ok(())
+    }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Campaign, SupplyShard};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct CreateSupplyShard<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(has_one = creator)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + SupplyShard::SIZE,
+        seeds = [b"shard", campaign.key().as_ref(), &[index]],
+        bump
+    )]
+    pub supply_shard: Account<'info, SupplyShard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates one supply shard. Capped campaigns split max_supply evenly, with the
+/// remainder going to the lowest indices; open edition shards are uncapped.
+pub fn create_supply_shard_instruction(ctx: Context<CreateSupplyShard>, index: u8) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        require!(campaign.supply_shards > 0, ErrorCode::ShardsNotConfigured);
+        require!(index < campaign.supply_shards, ErrorCode::InvalidShard);
+
+        let max_supply = if campaign.open_edition {
+            u64::MAX
+        } else {
+            let shards = campaign.supply_shards as u64;
+            let base = campaign.max_supply / shards;
+            let extra = u64::from((index as u64) < campaign.max_supply % shards);
+            base + extra
+        };
+
+        let shard = &mut ctx.accounts.supply_shard;
+        shard.index = index;
+        shard.minted = 0;
+        shard.max_supply = max_supply;
+        shard.bump = ctx.bumps.supply_shard;
+
+        Ok(())
+    }
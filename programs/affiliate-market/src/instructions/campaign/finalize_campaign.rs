@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_interface::Token2022;
+use spl_token_group_interface::instruction::update_group_max_size;
+
+use crate::state::{AssetStandard, Campaign, SupplyShard};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct FinalizeCampaign<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Collection mint - verified via campaign constraint, only needed
+    /// to resize a Token-2022 collection group
+    #[account(mut, address = campaign.collection_mint)]
+    pub collection_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Mint authority PDA - Token-2022 group update authority
+    #[account(
+        seeds = [b"mint_auth", campaign.key().as_ref()],
+        bump = campaign.mint_authority_bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+}
+
+/// Closes an open edition once its sale window has passed. Anyone can call it.
+/// The final count becomes max_supply, which stops further mints and freezes
+/// affiliate stats. Token-2022 collection groups are capped at that count.
+/// Sharded campaigns pass every supply shard in remaining accounts.
+pub fn finalize_campaign_instruction<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeCampaign<'info>>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        require!(campaign.open_edition, ErrorCode::NotOpenEdition);
+        require!(!campaign.finalized, ErrorCode::CampaignFinalized);
+        require!(Clock::get()?.unix_timestamp >= campaign.end_time, ErrorCode::SaleNotEnded);
+
+        // 1) Record the final count
+        if campaign.supply_shards > 0 {
+            campaign.minted = sum_supply_shards(campaign, ctx.remaining_accounts)?;
+        }
+        campaign.max_supply = campaign.minted;
+        campaign.finalized = true;
+
+        // 2) Set the collection size where the standard has an adjustable one
+        if campaign.asset_standard == AssetStandard::Token2022 {
+            let (Some(collection_mint), Some(mint_authority), Some(token_program)) = (
+                ctx.accounts.collection_mint.as_ref(),
+                ctx.accounts.mint_authority.as_ref(),
+                ctx.accounts.token_2022_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingCollectionAccounts);
+            };
+
+            let binding = campaign.key();
+            let seeds_for_mint_auth: &[&[u8]] = &[b"mint_auth", binding.as_ref(), &[campaign.mint_authority_bump]];
+
+            invoke_signed(
+                &update_group_max_size(
+                    &token_program.key(),
+                    &collection_mint.key(),
+                    &mint_authority.key(),
+                    campaign.minted,
+                ),
+                &[collection_mint.to_account_info(), mint_authority.to_account_info()],
+                &[seeds_for_mint_auth],
+            )?;
+        }
+
+        Ok(())
+    }
+
+
+/// Totals the minted counts of all of the campaign's supply shards, each passed
+/// exactly once.
+fn sum_supply_shards<'info>(campaign: &Account<'info, Campaign>, shards: &'info [AccountInfo<'info>]) -> Result<u64> {
+    require!(shards.len() == campaign.supply_shards as usize, ErrorCode::InvalidRemainingAccounts);
+
+    let mut seen = vec![false; shards.len()];
+    let mut minted: u64 = 0;
+    for info in shards {
+        let shard = Account::<SupplyShard>::try_from(info)?;
+        let expected = Pubkey::create_program_address(
+            &[b"shard", campaign.key().as_ref(), &[shard.index], &[shard.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::InvalidShard)?;
+        require_keys_eq!(info.key(), expected, ErrorCode::InvalidShard);
+
+        let slot = seen.get_mut(shard.index as usize).ok_or(ErrorCode::InvalidShard)?;
+        require!(!*slot, ErrorCode::InvalidShard);
+        *slot = true;
+
+        minted = minted.checked_add(shard.minted).ok_or(ErrorCode::MathOverflow)?;
+    }
+    Ok(minted)
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::affiliate::payment::grow_account;
+use crate::state::Campaign;
+
+#[derive(Accounts)]
+pub struct MigrateCampaign<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: a Campaign account of any layout, checked by owner and
+    /// discriminator since older layouts no longer deserialize
+    #[account(mut, owner = crate::ID)]
+    pub campaign: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a campaign created before its latest fields were added to the current
+/// size. New settings start off: no gates, hooks or bot tax, fixed pricing.
+/// Anyone may pay for it.
+pub fn migrate_campaign_instruction(ctx: Context<MigrateCampaign>) -> Result<()> {
+        let campaign = ctx.accounts.campaign.to_account_info();
+        require!(
+            campaign.try_borrow_data()?.starts_with(Campaign::DISCRIMINATOR),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        grow_account(
+            &ctx.accounts.payer.to_account_info(),
+            &campaign,
+            &ctx.accounts.system_program.to_account_info(),
+            8 + Campaign::SIZE,
+        )
+    }
@@ -1,2 +1,44 @@
 pub mod create_campaign;
-pub use create_campaign::*;
\ No newline at end of file
+pub use create_campaign::*;
+pub mod create_campaign_tree;
+pub use create_campaign_tree::*;
+pub mod create_print_master;
+pub use create_print_master::*;
+pub mod finalize_campaign;
+pub use finalize_campaign::*;
+pub mod configure_supply_shards;
+pub use configure_supply_shards::*;
+pub mod create_supply_shard;
+pub use create_supply_shard::*;
+pub mod set_rent_sponsor;
+pub use set_rent_sponsor::*;
+pub mod withdraw_rent_sponsor;
+pub use withdraw_rent_sponsor::*;
+pub mod set_max_per_wallet;
+pub use set_max_per_wallet::*;
+pub mod set_allowlist_root;
+pub use set_allowlist_root::*;
+pub mod set_phases;
+pub use set_phases::*;
+pub mod set_token_gate;
+pub use set_token_gate::*;
+pub mod set_voucher_signer;
+pub use set_voucher_signer::*;
+pub mod set_required_cosigner;
+pub use set_required_cosigner::*;
+pub mod set_bot_tax;
+pub use set_bot_tax::*;
+pub mod set_guards;
+pub use set_guards::*;
+pub mod set_guard_program;
+pub use set_guard_program::*;
+pub mod set_post_mint_hook;
+pub use set_post_mint_hook::*;
+pub mod create_coupon;
+pub use create_coupon::*;
+pub mod set_pricing;
+pub use set_pricing::*;
+pub mod withdraw_shard_proceeds;
+pub use withdraw_shard_proceeds::*;
+pub mod migrate_campaign;
+pub use migrate_campaign::*;
\ No newline at end of file
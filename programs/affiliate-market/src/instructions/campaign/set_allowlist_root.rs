@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetAllowlistRoot<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Sets or rotates the allowlist Merkle root. While a root is set,
+/// `process_mint` only sells to wallets with a valid proof; None reopens the
+/// sale. Usage is tracked per leaf, so an unchanged leaf keeps its count
+/// across rotations.
+pub fn set_allowlist_root_instruction(ctx: Context<SetAllowlistRoot>, root: Option<[u8; 32]>) -> Result<()> {
+        ctx.accounts.campaign.allowlist_root = root;
+
+        Ok(())
+    }
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetBotTax<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Sets the lamport penalty `process_mint` charges instead of failing when a
+/// sale gate rejects the mint. Zero turns bot tax off.
+pub fn set_bot_tax_instruction(ctx: Context<SetBotTax>, lamports: u64) -> Result<()> {
+        ctx.accounts.campaign.bot_tax = lamports;
+
+        Ok(())
+    }
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetGuardProgram<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Registers a program `process_mint` and `process_mint_batch` call through
+/// the `check_mint` guard interface before minting, or None to remove it.
+/// Other mint paths reject campaigns with a guard program.
+pub fn set_guard_program_instruction(ctx: Context<SetGuardProgram>, guard_program: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.campaign.guard_program = guard_program;
+
+        Ok(())
+    }
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::guards::{Guard, GuardSet};
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetGuards<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + GuardSet::SIZE,
+        seeds = [b"guard_set", campaign.key().as_ref()],
+        bump
+    )]
+    pub guard_set: Account<'info, GuardSet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the campaign's ordered guard list. `process_mint` runs the guards
+/// after its built-in checks; an empty list turns the guard set off.
+pub fn set_guards_instruction(ctx: Context<SetGuards>, guards: Vec<Guard>) -> Result<()> {
+        require!(guards.len() <= GuardSet::MAX_GUARDS, ErrorCode::TooManyGuards);
+
+        ctx.accounts.campaign.guarded = !guards.is_empty();
+        ctx.accounts.guard_set.guards = guards;
+
+        Ok(())
+    }
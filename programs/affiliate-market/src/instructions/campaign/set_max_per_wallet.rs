@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetMaxPerWallet<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Caps how many NFTs one wallet can receive through `process_mint`.
+/// Zero removes the cap.
+pub fn set_max_per_wallet_instruction(ctx: Context<SetMaxPerWallet>, max_per_wallet: u64) -> Result<()> {
+        ctx.accounts.campaign.max_per_wallet = max_per_wallet;
+
+        Ok(())
+    }
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Campaign, CampaignPhases, Phase};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetPhases<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CampaignPhases::SIZE,
+        seeds = [b"phases", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_phases: Account<'info, CampaignPhases>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the campaign's sale phases. Minted counts are kept by position,
+/// so caps cannot be reset by resubmitting a phase. An empty list turns
+/// phases off and falls back to the campaign price and commission.
+pub fn set_phases_instruction(ctx: Context<SetPhases>, mut phases: Vec<Phase>) -> Result<()> {
+        require!(phases.len() <= CampaignPhases::MAX_PHASES, ErrorCode::InvalidPhases);
+
+        let mut previous_end = i64::MIN;
+        for phase in &phases {
+            require!(phase.start_time < phase.end_time, ErrorCode::InvalidPhases);
+            require!(phase.start_time >= previous_end, ErrorCode::InvalidPhases);
+            require!(phase.affiliate_fee_bps <= 10000, ErrorCode::InvalidFee);
+            previous_end = phase.end_time;
+        }
+
+        let campaign_phases = &mut ctx.accounts.campaign_phases;
+        for (index, phase) in phases.iter_mut().enumerate() {
+            phase.minted = campaign_phases.phases.get(index).map_or(0, |old| old.minted);
+        }
+
+        ctx.accounts.campaign.phased = !phases.is_empty();
+        campaign_phases.phases = phases;
+
+        Ok(())
+    }
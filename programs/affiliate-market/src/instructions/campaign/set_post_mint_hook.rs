@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetPostMintHook<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Registers a program `process_mint` notifies through the `on_mint` hook
+/// interface after each mint, or None to remove it. The hook is called on
+/// every mint; with `fatal` a failure code it returns aborts the mint,
+/// otherwise it is logged and ignored.
+pub fn set_post_mint_hook_instruction(
+        ctx: Context<SetPostMintHook>,
+        post_mint_hook: Option<Pubkey>,
+        fatal: bool,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.post_mint_hook = post_mint_hook;
+        campaign.post_mint_hook_fatal = fatal;
+
+        Ok(())
+    }
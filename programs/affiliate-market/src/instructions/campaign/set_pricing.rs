@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Campaign, PricingMode};
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetPricing<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Chooses how the list price is computed. Locked once the first NFT is
+/// minted so auction receipts always settle against the same parameters.
+pub fn set_pricing_instruction(ctx: Context<SetPricing>, pricing: PricingMode) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        require!(campaign.minted == 0, ErrorCode::PricingLocked);
+
+        if let PricingMode::DutchAuction(auction) = &pricing {
+            require!(auction.interval > 0, ErrorCode::InvalidAuction);
+            require!(auction.step > 0, ErrorCode::InvalidAuction);
+            require!(auction.start_price >= auction.floor_price, ErrorCode::InvalidAuction);
+        }
+
+        campaign.pricing = pricing;
+        campaign.clearing_price = 0;
+
+        Ok(())
+    }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetRentSponsor<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Rent sponsor PDA - system account, funded by plain transfers
+    #[account(
+        seeds = [b"rent_sponsor", campaign.key().as_ref()],
+        bump
+    )]
+    pub rent_sponsor: UncheckedAccount<'info>,
+}
+
+/// Turns rent sponsorship on or off. While on, `process_mint` refunds the
+/// payer's rent from the sponsor PDA as long as it has funds.
+pub fn set_rent_sponsor_instruction(ctx: Context<SetRentSponsor>, enabled: bool) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.rent_sponsored = enabled;
+        campaign.rent_sponsor_bump = ctx.bumps.rent_sponsor;
+
+        Ok(())
+    }
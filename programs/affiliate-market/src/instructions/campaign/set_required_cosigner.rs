@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetRequiredCosigner<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Sets the key that must cosign every `process_mint`, or None to drop the
+/// requirement.
+pub fn set_required_cosigner_instruction(ctx: Context<SetRequiredCosigner>, required_cosigner: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.campaign.required_cosigner = required_cosigner;
+
+        Ok(())
+    }
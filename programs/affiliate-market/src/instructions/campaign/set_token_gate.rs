@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetTokenGate<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Restricts `process_mint` to holders of a verified NFT from
+/// `gate_collection`, or lifts the gate with None. With `single_use` each
+/// gating NFT can only be used for one mint.
+pub fn set_token_gate_instruction(
+        ctx: Context<SetTokenGate>,
+        gate_collection: Option<Pubkey>,
+        single_use: bool,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.gate_collection = gate_collection;
+        campaign.gate_single_use = single_use;
+
+        Ok(())
+    }
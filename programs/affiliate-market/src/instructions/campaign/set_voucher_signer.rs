@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Campaign;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetVoucherSigner<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        constraint = campaign.supply_shards == 0 @ ErrorCode::ShardedUnsupported
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Registers the ed25519 key that signs mint vouchers. While set, every
+/// `process_mint` needs a voucher from it; None turns vouchers off.
+pub fn set_voucher_signer_instruction(ctx: Context<SetVoucherSigner>, voucher_signer: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.campaign.voucher_signer = voucher_signer;
+
+        Ok(())
+    }
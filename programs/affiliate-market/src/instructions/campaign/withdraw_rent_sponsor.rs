@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::state::Campaign;
+
+#[derive(Accounts)]
+pub struct WithdrawRentSponsor<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(has_one = creator)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Rent sponsor PDA - system account
+    #[account(
+        mut,
+        seeds = [b"rent_sponsor", campaign.key().as_ref()],
+        bump
+    )]
+    pub rent_sponsor: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Returns unused sponsor funds to the creator.
+pub fn withdraw_rent_sponsor_instruction(ctx: Context<WithdrawRentSponsor>, amount: u64) -> Result<()> {
+        let binding = ctx.accounts.campaign.key();
+        let sponsor_seeds: &[&[u8]] = &[b"rent_sponsor", binding.as_ref(), &[ctx.bumps.rent_sponsor]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.rent_sponsor.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+                &[sponsor_seeds],
+            ),
+            amount,
+        )?;
+
+        Ok(())
+    }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Campaign, SupplyShard};
+
+#[derive(Accounts)]
+pub struct WithdrawShardProceeds<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(has_one = creator)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"shard", campaign.key().as_ref(), &[supply_shard.index]],
+        bump = supply_shard.bump
+    )]
+    pub supply_shard: Account<'info, SupplyShard>,
+}
+
+/// Moves the creator proceeds collected by a supply shard, everything above
+/// its rent-exempt minimum, to the creator.
+pub fn withdraw_shard_proceeds_instruction(ctx: Context<WithdrawShardProceeds>) -> Result<()> {
+        let shard_info = ctx.accounts.supply_shard.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(shard_info.data_len());
+        let proceeds = shard_info.lamports().saturating_sub(rent_exempt);
+
+        **shard_info.try_borrow_mut_lamports()? -= proceeds;
+        **ctx.accounts.creator.try_borrow_mut_lamports()? += proceeds;
+
+        Ok(())
+    }
@@ -2,29 +2,198 @@ use anchor_lang::prelude::*;
 mod instructions;
 mod state;
 mod error;
+mod constants;
+mod events;
+mod guards;
 use instructions::*;
+use state::{AssetStandard, Discount, Phase, PricingMode};
+use guards::Guard;
 declare_id!("6jxp4eoRZ8C7qVeXKyHk68YEmCoBVHR1AQxJ9Le4Aey1");
 
 #[program]
 pub mod affiliate_market{
  use super::*;
    
-pub fn process_mint(
-        ctx: Context<ProcessMint>, 
+#[allow(clippy::too_many_arguments)]
+pub fn process_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessMint<'info>>, 
         affiliate_maybe: Option<Pubkey>, 
         name: String, 
         symbol: String, 
-        uri: String
+        uri: String,
+        allowlist: Option<AllowlistProof>,
+        voucher: Option<MintVoucher>,
+        coupon_code: Option<String>,
     ) -> Result<()> {
-       process_mint_instruction(ctx, affiliate_maybe, name, symbol, uri)
+       process_mint_instruction(ctx, affiliate_maybe, name, symbol, uri, allowlist, voucher, coupon_code)
     }
+    #[allow(clippy::too_many_arguments)]
      pub fn create_campaign(
         ctx: Context<CreateCampaign>,
         price: u64,
         affiliate_fee_bps: u16,
         max_supply: u64,
+        asset_standard: AssetStandard,
+        rule_set: Option<Pubkey>,
+        open_edition_end_time: Option<i64>,
+        collection: Option<CollectionArgs>,
+    ) -> Result<()> {
+        create_campaign_instruction(
+            ctx,
+            price,
+            affiliate_fee_bps,
+            max_supply,
+            asset_standard,
+            rule_set,
+            open_edition_end_time,
+            collection,
+        )
+    }
+    pub fn process_mint_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessMintBatch<'info>>,
+        affiliate_maybe: Option<Pubkey>,
+        quantity: u64,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        process_mint_batch_instruction(ctx, affiliate_maybe, quantity, name, symbol, uri)
+    }
+    pub fn process_mint_core(
+        ctx: Context<ProcessMintCore>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        uri: String
+    ) -> Result<()> {
+        process_mint_core_instruction(ctx, affiliate_maybe, name, uri)
+    }
+    pub fn process_mint_compressed(
+        ctx: Context<ProcessMintCompressed>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        process_mint_compressed_instruction(ctx, affiliate_maybe, name, symbol, uri)
+    }
+    pub fn process_mint_token_2022(
+        ctx: Context<ProcessMintToken2022>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        process_mint_token_2022_instruction(ctx, affiliate_maybe, name, symbol, uri)
+    }
+    pub fn create_print_master(
+        ctx: Context<CreatePrintMaster>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        create_print_master_instruction(ctx, name, symbol, uri)
+    }
+    pub fn finalize_campaign<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeCampaign<'info>>) -> Result<()> {
+        finalize_campaign_instruction(ctx)
+    }
+    pub fn create_campaign_tree(
+        ctx: Context<CreateCampaignTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
     ) -> Result<()> {
-        create_campaign_instruction(ctx, price, affiliate_fee_bps, max_supply)
+        create_campaign_tree_instruction(ctx, max_depth, max_buffer_size)
+    }
+    pub fn configure_supply_shards(ctx: Context<ConfigureSupplyShards>, shard_count: u8) -> Result<()> {
+        configure_supply_shards_instruction(ctx, shard_count)
+    }
+    pub fn create_supply_shard(ctx: Context<CreateSupplyShard>, index: u8) -> Result<()> {
+        create_supply_shard_instruction(ctx, index)
+    }
+    pub fn process_mint_sharded(
+        ctx: Context<ProcessMintSharded>,
+        affiliate_maybe: Option<Pubkey>,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<()> {
+        process_mint_sharded_instruction(ctx, affiliate_maybe, name, symbol, uri)
+    }
+    pub fn verify_sharded_mint(ctx: Context<VerifyShardedMint>) -> Result<()> {
+        verify_sharded_mint_instruction(ctx)
+    }
+    pub fn withdraw_shard_proceeds(ctx: Context<WithdrawShardProceeds>) -> Result<()> {
+        withdraw_shard_proceeds_instruction(ctx)
+    }
+    pub fn set_rent_sponsor(ctx: Context<SetRentSponsor>, enabled: bool) -> Result<()> {
+        set_rent_sponsor_instruction(ctx, enabled)
+    }
+    pub fn withdraw_rent_sponsor(ctx: Context<WithdrawRentSponsor>, amount: u64) -> Result<()> {
+        withdraw_rent_sponsor_instruction(ctx, amount)
+    }
+    pub fn set_max_per_wallet(ctx: Context<SetMaxPerWallet>, max_per_wallet: u64) -> Result<()> {
+        set_max_per_wallet_instruction(ctx, max_per_wallet)
+    }
+    pub fn set_allowlist_root(ctx: Context<SetAllowlistRoot>, root: Option<[u8; 32]>) -> Result<()> {
+        set_allowlist_root_instruction(ctx, root)
+    }
+    pub fn set_phases(ctx: Context<SetPhases>, phases: Vec<Phase>) -> Result<()> {
+        set_phases_instruction(ctx, phases)
+    }
+    pub fn set_token_gate(
+        ctx: Context<SetTokenGate>,
+        gate_collection: Option<Pubkey>,
+        single_use: bool,
+    ) -> Result<()> {
+        set_token_gate_instruction(ctx, gate_collection, single_use)
+    }
+    pub fn set_voucher_signer(ctx: Context<SetVoucherSigner>, voucher_signer: Option<Pubkey>) -> Result<()> {
+        set_voucher_signer_instruction(ctx, voucher_signer)
+    }
+    pub fn set_required_cosigner(ctx: Context<SetRequiredCosigner>, required_cosigner: Option<Pubkey>) -> Result<()> {
+        set_required_cosigner_instruction(ctx, required_cosigner)
+    }
+    pub fn set_bot_tax(ctx: Context<SetBotTax>, lamports: u64) -> Result<()> {
+        set_bot_tax_instruction(ctx, lamports)
+    }
+    pub fn set_guards(ctx: Context<SetGuards>, guards: Vec<Guard>) -> Result<()> {
+        set_guards_instruction(ctx, guards)
+    }
+    pub fn set_guard_program(ctx: Context<SetGuardProgram>, guard_program: Option<Pubkey>) -> Result<()> {
+        set_guard_program_instruction(ctx, guard_program)
+    }
+    pub fn set_post_mint_hook(
+        ctx: Context<SetPostMintHook>,
+        post_mint_hook: Option<Pubkey>,
+        fatal: bool,
+    ) -> Result<()> {
+        set_post_mint_hook_instruction(ctx, post_mint_hook, fatal)
+    }
+    pub fn create_coupon(
+        ctx: Context<CreateCoupon>,
+        code_hash: [u8; 32],
+        discount: Discount,
+        max_uses: u64,
+        expiry: i64,
+    ) -> Result<()> {
+        create_coupon_instruction(ctx, code_hash, discount, max_uses, expiry)
+    }
+    pub fn set_affiliate_rebate(ctx: Context<SetAffiliateRebate>, buyer_rebate_bps: u16) -> Result<()> {
+        set_affiliate_rebate_instruction(ctx, buyer_rebate_bps)
+    }
+    pub fn set_pricing(ctx: Context<SetPricing>, pricing: PricingMode) -> Result<()> {
+        set_pricing_instruction(ctx, pricing)
+    }
+    pub fn claim_rebate(ctx: Context<ClaimRebate>) -> Result<()> {
+        claim_rebate_instruction(ctx)
+    }
+    pub fn quote_prices(ctx: Context<QuotePrices>, count: u8) -> Result<Vec<u64>> {
+        quote_prices_instruction(ctx, count)
+    }
+    pub fn migrate_campaign(ctx: Context<MigrateCampaign>) -> Result<()> {
+        migrate_campaign_instruction(ctx)
+    }
+    pub fn migrate_affiliate_stats(ctx: Context<MigrateAffiliateStats>) -> Result<()> {
+        migrate_affiliate_stats_instruction(ctx)
     }
    
 }
\ No newline at end of file
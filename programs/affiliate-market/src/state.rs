@@ -1,6 +1,132 @@
 use anchor_lang::prelude::*;
 
+use anchor_lang::solana_program::keccak::hash;
 
+use crate::error::ErrorCode;
+
+/// NFT standard minted by a campaign's mint instructions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AssetStandard {
+    /// Token Metadata NFT with master edition (`process_mint`, default)
+    TokenMetadata,
+    /// Metaplex Core asset in a Core collection (`process_mint_core`)
+    Core,
+    /// Bubblegum compressed NFT in the campaign merkle tree (`process_mint_compressed`)
+    Compressed,
+    /// Token Metadata programmable NFT with optional rule set (`process_mint`)
+    Programmable,
+    /// Token-2022 NFT with metadata and group member extensions (`process_mint_token_2022`)
+    Token2022,
+    /// Numbered print of the campaign master edition (`process_mint`)
+    PrintEdition,
+}
+
+/// How a campaign's list price is computed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PricingMode {
+    /// `campaign.price`
+    Fixed,
+    /// Price descending over time
+    DutchAuction(DutchAuction),
+    /// Price rising linearly with `campaign.minted`
+    LinearCurve(LinearCurve),
+    /// Price rising geometrically with `campaign.minted`
+    ExponentialCurve(ExponentialCurve),
+}
+
+impl PricingMode {
+    pub const SIZE: usize = 1 + DutchAuction::SIZE; // 42 bytes
+}
+
+/// Price starts at `start_price` and drops by `step` every `interval` seconds
+/// after `start_time`, never below `floor_price`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct DutchAuction {
+    pub start_time: i64,
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub interval: i64,
+    pub step: u64,
+    /// Everyone pays the clearing price; the excess above the floor is held
+    /// in an auction receipt and refunded through `claim_rebate`
+    pub rebate: bool,
+}
+
+impl DutchAuction {
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + 8 + 1; // 41 bytes
+
+    pub fn price_at(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.start_time).max(0) as u64;
+        let steps = elapsed / self.interval as u64;
+        let drop = steps.saturating_mul(self.step);
+        self.start_price.saturating_sub(drop).max(self.floor_price)
+    }
+
+    /// Price every rebate-mode buyer ends up paying. Once the auction is
+    /// closed (sold out or finalized) that is the last price paid,
+    /// `clearing_price`; otherwise the auction settles only at its floor.
+    pub fn settled_price(&self, closed: bool, clearing_price: u64, now: i64) -> Result<u64> {
+        if closed {
+            return Ok(clearing_price);
+        }
+        require!(self.price_at(now) == self.floor_price, ErrorCode::AuctionNotSettled);
+        Ok(self.floor_price)
+    }
+}
+
+/// price = base_price + slope * minted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct LinearCurve {
+    pub base_price: u64,
+    pub slope: u64,
+}
+
+impl LinearCurve {
+    pub fn price_at(&self, minted: u64) -> Result<u64> {
+        minted
+            .checked_mul(self.slope)
+            .and_then(|rise| rise.checked_add(self.base_price))
+            .ok_or(error!(ErrorCode::MathOverflow))
+    }
+}
+
+/// price = base_price * (1 + growth_bps / 10000) ^ minted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentialCurve {
+    pub base_price: u64,
+    pub growth_bps: u32,
+}
+
+impl ExponentialCurve {
+    /// Fixed-point scale of the growth factor. Small enough that the product
+    /// of two factors fits a u128 whenever the resulting price fits a u64.
+    const SCALE: u128 = 1_000_000_000;
+
+    pub fn price_at(&self, minted: u64) -> Result<u64> {
+        let rate = Self::SCALE + (self.growth_bps as u128) * Self::SCALE / 10_000;
+
+        // Exponentiation by squaring, rounding down at each step
+        let mul = |a: u128, b: u128| a.checked_mul(b).map(|p| p / Self::SCALE).ok_or(ErrorCode::MathOverflow);
+        let mut factor = Self::SCALE;
+        let mut base = rate;
+        let mut exp = minted;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                factor = mul(factor, base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = mul(base, base)?;
+            }
+        }
+
+        let price = (self.base_price as u128)
+            .checked_mul(factor)
+            .ok_or(ErrorCode::MathOverflow)?
+            / Self::SCALE;
+        Ok(u64::try_from(price).map_err(|_| ErrorCode::MathOverflow)?)
+    }
+}
 
 #[account]
 pub struct Campaign {
@@ -12,18 +138,415 @@ pub struct Campaign {
     pub max_supply: u64,
     pub mint_authority_bump: u8,
     pub collection_auth_bump: u8,
+    pub asset_standard: AssetStandard,
+    pub merkle_tree: Pubkey,
+    pub rule_set: Option<Pubkey>,
+    pub open_edition: bool,
+    pub end_time: i64,
+    pub finalized: bool,
+    pub supply_shards: u8,
+    pub rent_sponsored: bool,
+    pub rent_sponsor_bump: u8,
+    pub max_per_wallet: u64,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub phased: bool,
+    pub gate_collection: Option<Pubkey>,
+    pub gate_single_use: bool,
+    pub voucher_signer: Option<Pubkey>,
+    pub required_cosigner: Option<Pubkey>,
+    pub bot_tax: u64,
+    pub guarded: bool,
+    pub guard_program: Option<Pubkey>,
+    pub post_mint_hook: Option<Pubkey>,
+    pub post_mint_hook_fatal: bool,
+    pub pricing: PricingMode,
+    /// Latest Dutch auction price paid, the clearing price once sold out
+    pub clearing_price: u64,
 }
 
 impl Campaign {
-    pub const SIZE: usize = 32 + 32 + 8 + 2 + 8 + 8 + 1 + 1; // 92 bytes
+    pub const SIZE: usize = 32 + 32 + 8 + 2 + 8 + 8 + 1 + 1 + 1 + 32 + 33 + 1 + 8 + 1 + 1 + 1 + 1 + 8 + 33 + 1 + 33 + 1 + 33 + 33 + 8 + 1 + 33 + 33 + 1 + PricingMode::SIZE + 8; // 439 bytes
+
+    /// Fails once the campaign is finalized or an open edition's sale window
+    /// has passed.
+    pub fn check_sale_window(&self) -> Result<()> {
+        require!(!self.finalized, ErrorCode::CampaignFinalized);
+        if self.open_edition {
+            let now = Clock::get()?.unix_timestamp;
+            require!(now < self.end_time, ErrorCode::SaleEnded);
+        }
+        Ok(())
+    }
+
+    /// Price before phases, allowlists, vouchers and discounts.
+    pub fn list_price(&self, now: i64) -> Result<u64> {
+        self.price_for(self.minted, now)
+    }
+
+    /// List price of the item minted after `minted` earlier ones.
+    pub fn price_for(&self, minted: u64, now: i64) -> Result<u64> {
+        match &self.pricing {
+            PricingMode::Fixed => Ok(self.price),
+            PricingMode::DutchAuction(auction) => Ok(auction.price_at(now)),
+            PricingMode::LinearCurve(curve) => curve.price_at(minted),
+            PricingMode::ExponentialCurve(curve) => curve.price_at(minted),
+        }
+    }
+
+    /// True when a setting only `process_mint` applies is configured, which
+    /// sharded campaigns cannot use.
+    pub fn has_process_mint_settings(&self) -> bool {
+        self.rent_sponsored
+            || self.max_per_wallet > 0
+            || self.allowlist_root.is_some()
+            || self.phased
+            || self.gate_collection.is_some()
+            || self.voucher_signer.is_some()
+            || self.required_cosigner.is_some()
+            || self.bot_tax > 0
+            || self.guarded
+            || self.guard_program.is_some()
+            || self.post_mint_hook.is_some()
+            || self.pricing != PricingMode::Fixed
+    }
+
+    /// Like `check_supply`, for mint paths that apply none of `process_mint`'s
+    /// sale gates, phases or dynamic pricing.
+    pub fn check_mintable(&self, quantity: u64) -> Result<()> {
+        require!(self.guard_program.is_none(), ErrorCode::GuardProgramSale);
+        self.check_batch_mintable(quantity)
+    }
+
+    /// `check_mintable` for `process_mint_batch`, which calls the campaign
+    /// guard program itself.
+    pub fn check_batch_mintable(&self, quantity: u64) -> Result<()> {
+        require!(!self.phased, ErrorCode::PhasedSale);
+        require!(self.pricing == PricingMode::Fixed, ErrorCode::DynamicPricing);
+        require!(self.max_per_wallet == 0, ErrorCode::WalletLimitedSale);
+        require!(self.allowlist_root.is_none(), ErrorCode::AllowlistSale);
+        require!(self.gate_collection.is_none(), ErrorCode::TokenGatedSale);
+        require!(self.voucher_signer.is_none(), ErrorCode::VoucherSale);
+        require!(self.required_cosigner.is_none(), ErrorCode::CosignedSale);
+        require!(!self.guarded, ErrorCode::GuardedSale);
+        require!(self.post_mint_hook.is_none(), ErrorCode::HookedSale);
+        require!(self.bot_tax == 0, ErrorCode::BotTaxedSale);
+        self.check_supply(quantity)
+    }
+
+    /// Fails unless `quantity` more items can be minted now: open editions must
+    /// be inside their sale window, capped campaigns stay within max_supply.
+    /// Sharded campaigns count supply on their shards instead.
+    pub fn check_supply(&self, quantity: u64) -> Result<()> {
+        require!(self.supply_shards == 0, ErrorCode::ShardedSupply);
+        self.check_sale_window()?;
+        if !self.open_edition {
+            let minted = self.minted.checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+            require!(minted <= self.max_supply, ErrorCode::SoldOut);
+        }
+        Ok(())
+    }
 }
 
 #[account]
 pub struct AffiliateStats {
     pub total_mints: u64,
     pub total_earned: u64,
+    /// Share of the affiliate cut refunded to buyers, in basis points
+    pub buyer_rebate_bps: u16,
 }
 
 impl AffiliateStats {
-    pub const SIZE: usize = 8 + 8; // 16 bytes
+    pub const SIZE: usize = 8 + 8 + 2; // 18 bytes
+}
+
+/// Slice of a campaign's supply counter, so concurrent mints can write
+/// different accounts instead of all locking the campaign. Also holds the
+/// creator proceeds of its mints until `withdraw_shard_proceeds`.
+#[account]
+pub struct SupplyShard {
+    pub index: u8,
+    pub minted: u64,
+    pub max_supply: u64,
+    pub bump: u8,
+}
+
+impl SupplyShard {
+    pub const SIZE: usize = 1 + 8 + 8 + 1; // 18 bytes
+}
+
+/// Number of NFTs a wallet has received from a campaign.
+#[account]
+pub struct WalletMints {
+    pub minted: u64,
+}
+
+impl WalletMints {
+    pub const SIZE: usize = 8; // 8 bytes
+}
+
+/// Mints made against one allowlist leaf.
+#[account]
+pub struct AllowlistUsage {
+    pub minted: u64,
+}
+
+impl AllowlistUsage {
+    pub const SIZE: usize = 8; // 8 bytes
+}
+
+/// Marks a gating NFT as spent for one campaign.
+#[account]
+pub struct GateUsage {
+    pub used: bool,
+}
+
+impl GateUsage {
+    pub const SIZE: usize = 1; // 1 byte
+}
+
+/// Marks a voucher nonce as redeemed.
+#[account]
+pub struct VoucherNonce {
+    pub used: bool,
+}
+
+impl VoucherNonce {
+    pub const SIZE: usize = 1; // 1 byte
+}
+
+/// Escrow for a Dutch auction mint in rebate mode. Holds the lamports paid
+/// above the floor until `claim_rebate` settles them at the clearing price.
+#[account]
+pub struct AuctionReceipt {
+    pub campaign: Pubkey,
+    pub nft_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub affiliate: Option<Pubkey>,
+    pub affiliate_fee_bps: u16,
+    pub escrowed: u64,
+    pub bump: u8,
+}
+
+impl AuctionReceipt {
+    pub const SIZE: usize = 32 + 32 + 32 + 33 + 2 + 8 + 1; // 140 bytes
+}
+
+/// Discount granted by a coupon.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Discount {
+    /// Basis points off the price
+    Percent { bps: u16 },
+    /// Lamports off the price, down to zero
+    Fixed { lamports: u64 },
+}
+
+/// Discount code of a campaign, seeded by `[b"coupon", campaign, keccak(code)]`.
+#[account]
+pub struct Coupon {
+    pub discount: Discount,
+    /// Redemptions allowed, 0 for unlimited
+    pub max_uses: u64,
+    pub uses: u64,
+    /// Unix time after which the code is rejected, 0 for none
+    pub expiry: i64,
+}
+
+impl Coupon {
+    pub const SIZE: usize = 9 + 8 + 8 + 8; // 33 bytes
+
+    pub fn code_hash(code: &str) -> [u8; 32] {
+        hash(code.as_bytes()).to_bytes()
+    }
+
+    /// Checks the coupon can be redeemed now and returns the discounted price.
+    pub fn apply(&self, price: u64) -> Result<u64> {
+        require!(self.max_uses == 0 || self.uses < self.max_uses, ErrorCode::CouponExhausted);
+        if self.expiry != 0 {
+            require!(Clock::get()?.unix_timestamp < self.expiry, ErrorCode::CouponExpired);
+        }
+        let discount = match self.discount {
+            Discount::Percent { bps } => ((price as u128) * (bps as u128) / 10_000u128) as u64,
+            Discount::Fixed { lamports } => lamports,
+        };
+        Ok(price.saturating_sub(discount))
+    }
+}
+
+/// One sale phase. Phases override the campaign price, commission and
+/// allowlist while their window is open.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Phase {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub price: u64,
+    pub affiliate_fee_bps: u16,
+    /// Mints allowed in this phase, 0 for no cap beyond the campaign supply
+    pub max_mints: u64,
+    pub minted: u64,
+    /// Allowlist gating this phase, None for a public phase
+    pub allowlist_root: Option<[u8; 32]>,
+}
+
+impl Phase {
+    pub const SIZE: usize = 8 + 8 + 8 + 2 + 8 + 8 + 33; // 75 bytes
+}
+
+/// Ordered, non-overlapping sale phases of a campaign.
+#[account]
+pub struct CampaignPhases {
+    pub phases: Vec<Phase>,
+}
+
+impl CampaignPhases {
+    pub const MAX_PHASES: usize = 8;
+    pub const SIZE: usize = 4 + Phase::SIZE * Self::MAX_PHASES; // 604 bytes
+
+    /// Index of the phase whose window contains `now`.
+    pub fn active_phase(&self, now: i64) -> Result<usize> {
+        self.phases
+            .iter()
+            .position(|phase| phase.start_time <= now && now < phase.end_time)
+            .ok_or(error!(ErrorCode::NoActivePhase))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction() -> DutchAuction {
+        DutchAuction {
+            start_time: 1_000,
+            start_price: 10_000,
+            floor_price: 4_000,
+            interval: 60,
+            step: 1_000,
+            rebate: true,
+        }
+    }
+
+    #[test]
+    fn auction_starts_at_start_price() {
+        assert_eq!(auction().price_at(0), 10_000);
+        assert_eq!(auction().price_at(1_000), 10_000);
+        assert_eq!(auction().price_at(1_059), 10_000);
+    }
+
+    #[test]
+    fn auction_drops_one_step_per_interval() {
+        assert_eq!(auction().price_at(1_060), 9_000);
+        assert_eq!(auction().price_at(1_119), 9_000);
+        assert_eq!(auction().price_at(1_120), 8_000);
+    }
+
+    #[test]
+    fn auction_stops_at_floor() {
+        assert_eq!(auction().price_at(1_360), 4_000);
+        assert_eq!(auction().price_at(i64::MAX), 4_000);
+    }
+
+    #[test]
+    fn closed_auction_settles_at_last_price_paid() {
+        // Sold out above the floor: a late claim must not fall back to the floor
+        assert_eq!(auction().settled_price(true, 8_000, i64::MAX).unwrap(), 8_000);
+        assert_eq!(auction().settled_price(true, 8_000, 1_000).unwrap(), 8_000);
+    }
+
+    #[test]
+    fn open_auction_settles_only_at_floor() {
+        assert!(auction().settled_price(false, 8_000, 1_200).is_err());
+        assert_eq!(auction().settled_price(false, 5_000, 1_360).unwrap(), 4_000);
+    }
+
+    #[test]
+    fn linear_curve_rises_by_slope() {
+        let curve = LinearCurve { base_price: 100, slope: 10 };
+        assert_eq!(curve.price_at(0).unwrap(), 100);
+        assert_eq!(curve.price_at(3).unwrap(), 130);
+    }
+
+    #[test]
+    fn linear_curve_overflow_fails() {
+        let curve = LinearCurve { base_price: 1, slope: u64::MAX };
+        assert_eq!(curve.price_at(2).unwrap_err(), error!(ErrorCode::MathOverflow));
+        let curve = LinearCurve { base_price: u64::MAX, slope: 1 };
+        assert_eq!(curve.price_at(1).unwrap_err(), error!(ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn exponential_curve_starts_at_base_price() {
+        let curve = ExponentialCurve { base_price: 1_234, growth_bps: 500 };
+        assert_eq!(curve.price_at(0).unwrap(), 1_234);
+    }
+
+    #[test]
+    fn exponential_curve_matches_closed_form() {
+        // 2x per mint: base * 2^n
+        let doubling = ExponentialCurve { base_price: 1_000, growth_bps: 10_000 };
+        assert_eq!(doubling.price_at(1).unwrap(), 2_000);
+        assert_eq!(doubling.price_at(10).unwrap(), 1_024_000);
+
+        // 1.5x per mint: base * 1.5^n
+        let half = ExponentialCurve { base_price: 1_000, growth_bps: 5_000 };
+        assert_eq!(half.price_at(2).unwrap(), 2_250);
+        assert_eq!(half.price_at(3).unwrap(), 3_375);
+
+        // 1% per mint: 1e9 * 1.01^5 = 1_051_010_050.1
+        let percent = ExponentialCurve { base_price: 1_000_000_000, growth_bps: 100 };
+        assert_eq!(percent.price_at(5).unwrap(), 1_051_010_050);
+    }
+
+    #[test]
+    fn exponential_curve_rounds_down() {
+        let half = ExponentialCurve { base_price: 3, growth_bps: 5_000 };
+        assert_eq!(half.price_at(1).unwrap(), 4);
+        let tiny = ExponentialCurve { base_price: 9_999, growth_bps: 1 };
+        assert_eq!(tiny.price_at(1).unwrap(), 9_999);
+    }
+
+    #[test]
+    fn exponential_curve_overflow_fails() {
+        // 2^63 still fits a u64 price, 2^64 does not
+        let doubling = ExponentialCurve { base_price: 1, growth_bps: 10_000 };
+        assert_eq!(doubling.price_at(63).unwrap(), 1 << 63);
+        assert_eq!(doubling.price_at(64).unwrap_err(), error!(ErrorCode::MathOverflow));
+        let sol = ExponentialCurve { base_price: 1_000_000_000, growth_bps: 10_000 };
+        assert_eq!(sol.price_at(34).unwrap(), 1_000_000_000 << 34);
+        assert_eq!(sol.price_at(35).unwrap_err(), error!(ErrorCode::MathOverflow));
+        // The fixed-point factor itself overflows u128
+        assert_eq!(doubling.price_at(u64::MAX).unwrap_err(), error!(ErrorCode::MathOverflow));
+    }
+
+    fn coupon(discount: Discount) -> Coupon {
+        Coupon { discount, max_uses: 0, uses: 0, expiry: 0 }
+    }
+
+    #[test]
+    fn percent_coupon_rounds_discount_down() {
+        let ten_percent = coupon(Discount::Percent { bps: 1_000 });
+        assert_eq!(ten_percent.apply(10_000).unwrap(), 9_000);
+        // 10% of 1_005 is 100.5, the buyer gets 100 off
+        assert_eq!(ten_percent.apply(1_005).unwrap(), 905);
+        assert_eq!(ten_percent.apply(9).unwrap(), 9);
+        assert_eq!(coupon(Discount::Percent { bps: 10_000 }).apply(10_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn fixed_coupon_subtracts_lamports() {
+        assert_eq!(coupon(Discount::Fixed { lamports: 2_500 }).apply(10_000).unwrap(), 7_500);
+    }
+
+    #[test]
+    fn discount_above_price_floors_at_zero() {
+        assert_eq!(coupon(Discount::Fixed { lamports: 10_001 }).apply(10_000).unwrap(), 0);
+        assert_eq!(coupon(Discount::Percent { bps: 10_000 }).apply(u64::MAX).unwrap(), 0);
+    }
+
+    #[test]
+    fn exhausted_coupon_fails() {
+        let limited = Coupon { max_uses: 2, uses: 2, ..coupon(Discount::Fixed { lamports: 1 }) };
+        assert_eq!(limited.apply(10).unwrap_err(), error!(ErrorCode::CouponExhausted));
+        let unlimited = Coupon { uses: 1_000, ..coupon(Discount::Fixed { lamports: 1 }) };
+        assert_eq!(unlimited.apply(10).unwrap(), 9);
+    }
 }
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+declare_id!("FefZDH3j2iEvmZkEhB5KPArzvEyCPYmk8MTZgU8H27Wr");
+
+/// Largest quantity accepted in one mint.
+pub const MAX_QUANTITY: u64 = 5;
+
+/// Example guard for affiliate-market campaigns. `process_mint` calls
+/// `check_mint` with the buyer and campaign accounts; an error aborts the mint.
+/// This guard blocks self-referrals and caps the quantity per mint.
+#[program]
+pub mod sample_guard {
+    use super::*;
+
+    pub fn check_mint(ctx: Context<CheckMint>, affiliate: Option<Pubkey>, quantity: u64) -> Result<()> {
+        check(&ctx.accounts.buyer.key(), affiliate, quantity)
+    }
+}
+
+#[derive(Accounts)]
+pub struct CheckMint<'info> {
+    /// CHECK: Buyer wallet, passed without its signature
+    pub buyer: UncheckedAccount<'info>,
+    /// CHECK: Campaign being minted from, available for campaign-specific rules
+    pub campaign: UncheckedAccount<'info>,
+}
+
+/// Guard rule, kept separate from the accounts so it can be tested directly.
+pub fn check(buyer: &Pubkey, affiliate: Option<Pubkey>, quantity: u64) -> Result<()> {
+    require!(quantity > 0 && quantity <= MAX_QUANTITY, GuardError::InvalidQuantity);
+    require!(affiliate != Some(*buyer), GuardError::SelfReferral);
+    Ok(())
+}
+
+#[error_code]
+pub enum GuardError {
+    #[msg("Quantity is outside the allowed range")]
+    InvalidQuantity,
+    #[msg("Buyer cannot be their own affiliate")]
+    SelfReferral,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn check_mint_matches_guard_interface() {
+        // affiliate-market calls guards with this discriminator
+        assert_eq!(instruction::CheckMint::DISCRIMINATOR, [111, 157, 230, 142, 6, 58, 200, 73]);
+    }
+
+    #[test]
+    fn allows_affiliate_mint() {
+        let buyer = Pubkey::new_unique();
+        assert!(check(&buyer, Some(Pubkey::new_unique()), 1).is_ok());
+        assert!(check(&buyer, None, MAX_QUANTITY).is_ok());
+    }
+
+    #[test]
+    fn rejects_self_referral() {
+        let buyer = Pubkey::new_unique();
+        assert_eq!(check(&buyer, Some(buyer), 1), Err(GuardError::SelfReferral.into()));
+    }
+
+    #[test]
+    fn rejects_quantity_out_of_range() {
+        let buyer = Pubkey::new_unique();
+        assert_eq!(check(&buyer, None, 0), Err(GuardError::InvalidQuantity.into()));
+        assert_eq!(check(&buyer, None, MAX_QUANTITY + 1), Err(GuardError::InvalidQuantity.into()));
+    }
+}
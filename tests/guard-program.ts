@@ -0,0 +1,137 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { AffiliateMarket } from "../target/types/affiliate_market";
+import { SampleGuard } from "../target/types/sample_guard";
+
+const { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } = anchor.web3;
+const { TOKEN_PROGRAM_ID, ASSOCIATED_PROGRAM_ID, associatedAddress } = anchor.utils.token;
+const TOKEN_METADATA_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+// Token Metadata is cloned into the local validator by Anchor.toml
+describe("process_mint guard program", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.affiliateMarket as Program<AffiliateMarket>;
+  const guard = anchor.workspace.sampleGuard as Program<SampleGuard>;
+  const creator = provider.wallet.publicKey;
+  const collectionMint = Keypair.generate();
+
+  const pda = (seeds: Buffer[], programId = program.programId) =>
+    PublicKey.findProgramAddressSync(seeds, programId)[0];
+  const metadataOf = (mint: anchor.web3.PublicKey) =>
+    pda([Buffer.from("metadata"), TOKEN_METADATA_ID.toBuffer(), mint.toBuffer()], TOKEN_METADATA_ID);
+  const editionOf = (mint: anchor.web3.PublicKey) =>
+    pda(
+      [Buffer.from("metadata"), TOKEN_METADATA_ID.toBuffer(), mint.toBuffer(), Buffer.from("edition")],
+      TOKEN_METADATA_ID
+    );
+
+  const campaign = pda([Buffer.from("campaign"), collectionMint.publicKey.toBuffer()]);
+  const collectionAuthority = pda([Buffer.from("collection_auth"), campaign.toBuffer()]);
+  const mintAuthority = pda([Buffer.from("mint_auth"), campaign.toBuffer()]);
+
+  const expectError = async (tx: Promise<unknown>, code: string) => {
+    try {
+      await tx;
+      assert.fail(`expected ${code}`);
+    } catch (err) {
+      const logs: string[] = err.logs ?? err.transactionLogs ?? [];
+      assert.isTrue(logs.some((log) => log.includes(code)), `expected ${code} in logs`);
+    }
+  };
+
+  const processMint = async (
+    affiliateReceiver: anchor.web3.PublicKey,
+    affiliate: anchor.web3.PublicKey | null,
+    guardProgram: anchor.web3.PublicKey | null = guard.programId
+  ) => {
+    const { minted } = await program.account.campaign.fetch(campaign);
+    const nftMint = pda([Buffer.from("nft_mint"), campaign.toBuffer(), minted.toArrayLike(Buffer, "le", 8)]);
+    return program.methods
+      .processMint(affiliate, "Item", "ITEM", "https://example.com/item.json", null, null, null)
+      .accountsPartial({
+        payer: creator,
+        buyer: creator,
+        campaign,
+        creator,
+        affiliateReceiver,
+        nftMint,
+        buyerAta: associatedAddress({ mint: nftMint, owner: creator }),
+        mintAuthority,
+        metadata: metadataOf(nftMint),
+        masterEdition: editionOf(nftMint),
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: metadataOf(collectionMint.publicKey),
+        collectionMasterEdition: editionOf(collectionMint.publicKey),
+        collectionAuthority,
+        guardProgram,
+        affiliateStats: pda([Buffer.from("affiliate"), campaign.toBuffer(), affiliateReceiver.toBuffer()]),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .preInstructions([anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+      .rpc();
+  };
+
+  before(async () => {
+    await program.methods
+      .createCampaign(new anchor.BN(1_000_000), 1000, new anchor.BN(10), { tokenMetadata: {} }, null, null, {
+        name: "Guarded",
+        symbol: "GRD",
+        uri: "https://example.com/collection.json",
+      })
+      .accountsPartial({
+        creator,
+        campaign,
+        collectionMint: collectionMint.publicKey,
+        collectionAuthority,
+        mintAuthority,
+        collectionTokenAccount: associatedAddress({ mint: collectionMint.publicKey, owner: creator }),
+        collectionMetadata: metadataOf(collectionMint.publicKey),
+        collectionMasterEdition: editionOf(collectionMint.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint])
+      .preInstructions([anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+      .rpc();
+
+    await program.methods.setGuardProgram(guard.programId).accountsPartial({ creator, campaign }).rpc();
+  });
+
+  it("mints when the guard allows it", async () => {
+    const affiliate = Keypair.generate().publicKey;
+    await processMint(affiliate, affiliate);
+
+    const { minted } = await program.account.campaign.fetch(campaign);
+    assert.equal(minted.toNumber(), 1);
+  });
+
+  it("aborts the mint when the guard rejects self-referral", async () => {
+    await expectError(processMint(creator, creator), "SelfReferral");
+
+    const { minted } = await program.account.campaign.fetch(campaign);
+    assert.equal(minted.toNumber(), 1);
+  });
+
+  it("rejects an affiliate other than the paid receiver", async () => {
+    const affiliate = Keypair.generate().publicKey;
+    await expectError(processMint(creator, affiliate), "AffiliateMismatch");
+
+    const { minted } = await program.account.campaign.fetch(campaign);
+    assert.equal(minted.toNumber(), 1);
+  });
+
+  it("requires the registered guard program", async () => {
+    const affiliate = Keypair.generate().publicKey;
+    await expectError(processMint(affiliate, affiliate, null), "MissingGuardProgram");
+  });
+});
@@ -0,0 +1,46 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { SampleGuard } from "../target/types/sample_guard";
+
+describe("sample-guard", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.sampleGuard as Program<SampleGuard>;
+  const buyer = provider.wallet.publicKey;
+  const campaign = anchor.web3.Keypair.generate().publicKey;
+
+  const checkMint = (affiliate: anchor.web3.PublicKey | null, quantity: number) =>
+    program.methods
+      .checkMint(affiliate, new anchor.BN(quantity))
+      .accounts({ buyer, campaign })
+      .rpc();
+
+  it("allows a mint through another affiliate", async () => {
+    await checkMint(anchor.web3.Keypair.generate().publicKey, 1);
+  });
+
+  it("allows a mint without an affiliate", async () => {
+    await checkMint(null, 5);
+  });
+
+  it("rejects self-referral", async () => {
+    try {
+      await checkMint(buyer, 1);
+      assert.fail("expected SelfReferral");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "SelfReferral");
+    }
+  });
+
+  it("rejects quantities above the cap", async () => {
+    try {
+      await checkMint(null, 6);
+      assert.fail("expected InvalidQuantity");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidQuantity");
+    }
+  });
+});